@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc::runner;
+use aoc::y2025::day05::SOLUTIONS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    runner::run_parts(&SOLUTIONS, input);
+});
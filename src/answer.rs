@@ -0,0 +1,150 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(value) => Some(*value as i128),
+            Answer::Signed(value) => Some(*value as i128),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+// Text is never compared with numbers; answers read from files go through
+// `FromStr`, which already turns numeric text into a number.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => match (self.as_integer(), other.as_integer()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(value) = s.parse::<u64>() {
+            return Ok(Answer::Unsigned(value));
+        }
+        if let Ok(value) = s.parse::<i64>() {
+            return Ok(Answer::Signed(value));
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Unsigned(value as u64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Signed(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Unsigned(1135).to_string(), "1135");
+        assert_eq!(Answer::Signed(-4).to_string(), "-4");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_numeric_equality() {
+        assert_eq!(Answer::Unsigned(42), Answer::Signed(42));
+        assert_eq!(Answer::from(42usize), Answer::from(42i32));
+        assert_ne!(Answer::Signed(-1), Answer::Unsigned(u64::MAX));
+    }
+
+    #[test]
+    fn test_text_equality() {
+        assert_ne!(Answer::Text("42".to_string()), Answer::Unsigned(42));
+        assert_ne!(
+            Answer::Text("042".to_string()),
+            Answer::Text("42".to_string())
+        );
+        assert_eq!("042".parse::<Answer>().unwrap(), Answer::Unsigned(42));
+        assert_eq!(" 42 ".parse::<Answer>().unwrap(), "42".parse().unwrap());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("1135".parse(), Ok(Answer::Unsigned(1135))));
+        assert!(matches!("-7".parse(), Ok(Answer::Signed(-7))));
+        assert!(matches!("ABC".parse(), Ok(Answer::Text(text)) if text == "ABC"));
+    }
+
+    #[test]
+    fn test_unsolved_equality() {
+        assert_eq!(Answer::Unsolved, Answer::Unsolved);
+        assert_ne!(Answer::Unsolved, Answer::Unsigned(0));
+        assert_ne!(Answer::Text("".to_string()), Answer::Unsolved);
+        assert!(!Answer::Unsolved.is_solved());
+    }
+}
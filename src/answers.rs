@@ -0,0 +1,152 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::runner::Outcome;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).context("invalid answers file")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers: {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("in {}", path.display()))
+    }
+
+    // Entries for other inputs of the same day are skipped, since one file can
+    // hold answers for several accounts.
+    pub fn entries_for(
+        &self,
+        input_hash: &str,
+        year: u16,
+        day: u8,
+    ) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| {
+            entry.input == input_hash && (entry.year, entry.day) == (year, day)
+        })
+    }
+
+    pub fn expected(&self, input_hash: &str, year: u16, day: u8, part: u8) -> Option<Answer> {
+        self.entries_for(input_hash, year, day)
+            .find(|entry| entry.part == part)
+            .and_then(|entry| entry.answer.parse().ok())
+    }
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: Answer },
+    Unknown,
+    Failed,
+}
+
+pub fn verify(outcome: &Outcome, answers: &Answers) -> Verdict {
+    let Ok(actual) = &outcome.answer else {
+        return Verdict::Failed;
+    };
+
+    let solution = outcome.solution;
+    let expected = outcome.input_hash.as_deref().and_then(|input_hash| {
+        answers.expected(input_hash, solution.year(), solution.day(), solution.part())
+    });
+
+    match expected {
+        Some(expected) if expected == *actual => Verdict::Correct,
+        Some(expected) => Verdict::Incorrect { expected },
+        None => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[[answer]]
+year = 2025
+day = 1
+part = 1
+input = "abc"
+answer = "1135"
+
+[[answer]]
+year = 2025
+day = 1
+part = 2
+input = "abc"
+answer = "6558"
+"#;
+
+    #[test]
+    fn test_expected() -> Result<()> {
+        let answers = Answers::parse(ANSWERS)?;
+        assert_eq!(
+            answers.expected("abc", 2025, 1, 1),
+            Some(Answer::Unsigned(1135))
+        );
+        assert_eq!(
+            answers.expected("abc", 2025, 1, 2),
+            Some(Answer::Unsigned(6558))
+        );
+        assert_eq!(answers.expected("def", 2025, 1, 1), None);
+        assert_eq!(answers.expected("abc", 2025, 2, 1), None);
+        assert_eq!(answers.expected("abc", 2024, 1, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_entries_for_one_input() -> Result<()> {
+        let other_input =
+            "[[answer]]\nyear = 2025\nday = 1\npart = 1\ninput = \"def\"\nanswer = \"7\"\n";
+        let answers = Answers::parse(&format!("{}\n{}", ANSWERS, other_input))?;
+        let parts = |input_hash| {
+            answers
+                .entries_for(input_hash, 2025, 1)
+                .map(|entry| (entry.part, entry.answer.as_str()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(parts("abc"), vec![(1, "1135"), (2, "6558")]);
+        assert_eq!(parts("def"), vec![(1, "7")]);
+        assert_eq!(
+            answers.expected("def", 2025, 1, 1),
+            Some(Answer::Unsigned(7))
+        );
+        assert!(answers.entries_for("abc", 2025, 2).next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_empty() -> Result<()> {
+        let answers = Answers::parse("")?;
+        assert!(answers.entries.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("[[answer]]\nyear = 2025\nday = \"one\"").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::bench::Benchmark;
+
+pub const DEFAULT_BASELINES_DIR: &str = ".aoc/baselines";
+
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub stddev_ns: u64,
+    pub samples: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<Entry>,
+}
+
+fn baseline_path(dir: &Path, name: &str) -> Result<PathBuf> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+    {
+        bail!("invalid baseline name: {:?}", name);
+    }
+    Ok(dir.join(format!("{}.json", name)))
+}
+
+impl Baseline {
+    pub fn from_benchmarks(benchmarks: &[Benchmark]) -> Self {
+        let entries = benchmarks
+            .iter()
+            .filter_map(|benchmark| {
+                let stats = benchmark.stats.as_ref().ok()?;
+                Some(Entry {
+                    year: benchmark.solution.year(),
+                    day: benchmark.solution.day(),
+                    part: benchmark.solution.part(),
+                    mean_ns: stats.mean.as_nanos() as u64,
+                    median_ns: stats.median.as_nanos() as u64,
+                    stddev_ns: stats.stddev.as_nanos() as u64,
+                    samples: stats.samples,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    pub fn load(dir: &Path, name: &str) -> Result<Self> {
+        let path = baseline_path(dir, name)?;
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read baseline: {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("invalid baseline: {}", path.display()))
+    }
+
+    pub fn save(&self, dir: &Path, name: &str) -> Result<PathBuf> {
+        let path = baseline_path(dir, name)?;
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write baseline: {}", path.display()))?;
+        Ok(path)
+    }
+
+    pub fn find(&self, year: u16, day: u8, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| (entry.year, entry.day, entry.part) == (year, day, part))
+    }
+
+    pub fn median(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.find(year, day, part)
+            .map(|entry| Duration::from_nanos(entry.median_ns))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Faster(f64),
+    Slower(f64),
+    Unchanged(f64),
+    Missing,
+}
+
+pub fn compare(benchmark: &Benchmark, baseline: &Baseline, threshold: f64) -> Change {
+    let solution = benchmark.solution;
+    let (Ok(stats), Some(before)) = (
+        &benchmark.stats,
+        baseline.median(solution.year(), solution.day(), solution.part()),
+    ) else {
+        return Change::Missing;
+    };
+    if before.is_zero() {
+        return Change::Missing;
+    }
+
+    let percent = (stats.median.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    if percent > threshold {
+        Change::Slower(percent)
+    } else if percent < -threshold {
+        Change::Faster(percent)
+    } else {
+        Change::Unchanged(percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::bench::Stats;
+    use crate::registry;
+
+    fn benchmark(day: u8, part: u8, median: f64) -> Benchmark {
+        Benchmark {
+            solution: registry::find(2025, day, part).unwrap(),
+            stats: Ok(Stats::from_samples(&[median], 1)),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::from_benchmarks(&[benchmark(1, 1, 1.0), benchmark(1, 2, 1.0)]);
+
+        assert!(matches!(
+            compare(&benchmark(1, 1, 1.2), &baseline, 5.0),
+            Change::Slower(p) if (p - 20.0).abs() < 1e-6
+        ));
+        assert!(matches!(
+            compare(&benchmark(1, 2, 0.5), &baseline, 5.0),
+            Change::Faster(p) if (p + 50.0).abs() < 1e-6
+        ));
+        assert!(matches!(
+            compare(&benchmark(1, 1, 1.03), &baseline, 5.0),
+            Change::Unchanged(_)
+        ));
+        assert_eq!(
+            compare(&benchmark(2, 1, 1.0), &baseline, 5.0),
+            Change::Missing
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        let baseline = Baseline::from_benchmarks(&[benchmark(3, 2, 0.25)]);
+
+        let path = baseline.save(&dir, "main")?;
+        assert_eq!(path, dir.join("main.json"));
+
+        let loaded = Baseline::load(&dir, "main")?;
+        assert_eq!(loaded.median(2025, 3, 2), Some(Duration::from_millis(250)));
+        assert!(loaded.find(2025, 3, 1).is_none());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_name() {
+        assert!(Baseline::load(Path::new("."), "../escape").is_err());
+        assert!(Baseline::default().save(Path::new("."), "").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::input;
+use crate::solution::Solution;
+
+const MIN_SAMPLES: usize = 10;
+
+pub struct Config {
+    pub warmup: Duration,
+    pub measurement: Duration,
+    pub samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(300),
+            measurement: Duration::from_secs(1),
+            samples: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations: u64,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub outliers: usize,
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[f64], iterations: u64) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let outliers = sorted.iter().filter(|&&s| s < low || s > high).count();
+
+        Self {
+            samples: sorted.len(),
+            iterations,
+            mean: Duration::from_secs_f64(mean),
+            median: Duration::from_secs_f64(percentile(&sorted, 0.5)),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: Duration::from_secs_f64(sorted[0]),
+            max: Duration::from_secs_f64(sorted[sorted.len() - 1]),
+            outliers,
+        }
+    }
+}
+
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut warmup_iterations = 0u64;
+    while warmup_iterations == 0 || start.elapsed() < config.warmup {
+        black_box(f());
+        warmup_iterations += 1;
+    }
+    let estimate = (start.elapsed().as_secs_f64() / warmup_iterations as f64).max(1e-9);
+
+    let budget = (config.measurement.as_secs_f64() / estimate) as usize;
+    let samples = budget.clamp(config.samples.clamp(1, MIN_SAMPLES), config.samples.max(1));
+    let iterations_per_sample =
+        ((config.measurement.as_secs_f64() / samples as f64 / estimate) as u64).max(1);
+
+    let mut times = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        for _ in 0..iterations_per_sample {
+            black_box(f());
+        }
+        times.push(start.elapsed().as_secs_f64() / iterations_per_sample as f64);
+    }
+
+    Stats::from_samples(&times, samples as u64 * iterations_per_sample)
+}
+
+pub struct Benchmark {
+    pub solution: &'static dyn Solution,
+    pub stats: Result<Stats>,
+}
+
+pub fn run(config: &Config, solution: &'static dyn Solution, inputs_dir: &Path) -> Benchmark {
+    let stats = input::read_input(inputs_dir, solution.year(), solution.day()).and_then(|input| {
+        solution.run(&input)?;
+        Ok(measure(config, || solution.run(black_box(&input))))
+    });
+
+    Benchmark { solution, stats }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[3.0, 1.0, 2.0, 5.0, 4.0], 5);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.mean, Duration::from_secs(3));
+        assert_eq!(stats.median, Duration::from_secs(3));
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.max, Duration::from_secs(5));
+        assert!((stats.stddev.as_secs_f64() - 2.5f64.sqrt()).abs() < 1e-9);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn test_stats_outliers() {
+        let stats = Stats::from_samples(&[1.0, 1.1, 0.9, 1.0, 1.05, 0.95, 9.0], 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[0.5], 1);
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.median, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_measure() {
+        let config = Config {
+            warmup: Duration::from_millis(1),
+            measurement: Duration::from_millis(5),
+            samples: 20,
+        };
+        let mut calls = 0u64;
+        let stats = measure(&config, || calls += 1);
+        assert!(stats.samples >= MIN_SAMPLES && stats.samples <= 20);
+        assert!(calls >= stats.iterations);
+    }
+}
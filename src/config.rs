@@ -0,0 +1,55 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub const DEFAULT_STATE_DIR: &str = ".aoc";
+
+pub const DEFAULT_CONFIG_FILE: &str = ".aoc/config.toml";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).context("invalid config file")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("failed to read config: {}", path.display()));
+            }
+        };
+        Self::parse(&text).with_context(|| format!("in {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let config = Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080\"")?;
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost:8080"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing() -> Result<()> {
+        let config = Config::load(Path::new("does-not-exist/config.toml"))?;
+        assert!(config.session.is_none());
+        assert!(config.base_url.is_none());
+        Ok(())
+    }
+}
@@ -1,5 +0,0 @@
-pub mod part1;
-pub mod part2;
-
-#[cfg(test)]
-mod bench;
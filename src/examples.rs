@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::answer::Answer;
+
+pub const DEFAULT_EXAMPLES_DIR: &str = "examples";
+
+#[derive(Debug, Deserialize)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<Answer> {
+        let answer = match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        };
+        answer.and_then(|answer| answer.parse().ok())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    pub entries: Vec<Example>,
+}
+
+pub fn examples_path(examples_dir: &Path, year: u16, day: u8) -> PathBuf {
+    examples_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.toml", day))
+}
+
+impl Examples {
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).context("invalid examples file")
+    }
+
+    pub fn load(examples_dir: &Path, year: u16, day: u8) -> Result<Self> {
+        let path = examples_path(examples_dir, year, day);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read examples: {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("in {}", path.display()))
+    }
+
+    pub fn for_part(&self, part: u8) -> impl Iterator<Item = (&Example, Answer)> {
+        self.entries
+            .iter()
+            .filter_map(move |example| Some((example, example.expected(part)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &str = r#"
+[[example]]
+part1 = "5"
+input = '''
+you: out
+'''
+
+[[example]]
+part1 = "7"
+part2 = "-2"
+input = '''
+svr: out
+'''
+"#;
+
+    #[test]
+    fn test_for_part() -> Result<()> {
+        let examples = Examples::parse(EXAMPLES)?;
+        assert_eq!(examples.entries[0].input, "you: out\n");
+
+        let part1 = examples
+            .for_part(1)
+            .map(|(_, answer)| answer)
+            .collect::<Vec<_>>();
+        assert_eq!(part1, vec![Answer::Unsigned(5), Answer::Unsigned(7)]);
+
+        let part2 = examples.for_part(2).collect::<Vec<_>>();
+        assert_eq!(part2.len(), 1);
+        assert_eq!(part2[0].0.input, "svr: out\n");
+        assert_eq!(part2[0].1, Answer::Signed(-2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples_path() {
+        assert_eq!(
+            examples_path(Path::new("examples"), 2025, 7),
+            Path::new("examples/2025/day07.toml")
+        );
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let error = Examples::load(Path::new("does-not-exist"), 2025, 1).unwrap_err();
+        assert!(error.to_string().contains("does-not-exist/2025/day01.toml"));
+    }
+}
@@ -0,0 +1,227 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, bail};
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+use crate::input;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(5);
+
+const LAST_REQUEST_FILE: &str = "last-request";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub throttle: Duration,
+    pub state_dir: PathBuf,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, state_dir: &Path) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            throttle: DEFAULT_THROTTLE,
+            state_dir: state_dir.to_path_buf(),
+            agent,
+        }
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    fn wait_for_throttle(&self) -> Result<()> {
+        let path = self.state_dir.join(LAST_REQUEST_FILE);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.throttle).checked_sub(now)) {
+            thread::sleep(wait);
+        }
+
+        fs::create_dir_all(&self.state_dir)
+            .with_context(|| format!("failed to create directory: {}", self.state_dir.display()))?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::write(&path, now.as_millis().to_string())
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn get(&self, url: &str) -> Result<String> {
+        self.wait_for_throttle()?;
+
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .call();
+        read_response(url, response)
+    }
+
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.wait_for_throttle()?;
+
+        let response = self
+            .agent
+            .post(url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied());
+        read_response(url, response)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let input = self.get(&self.input_url(year, day))?;
+        if input.trim().is_empty() {
+            bail!("empty input for {} day {}", year, day);
+        }
+        Ok(input)
+    }
+}
+
+fn read_response(url: &str, response: Result<Response<Body>, ureq::Error>) -> Result<String> {
+    let mut response = response.with_context(|| format!("request failed: {}", url))?;
+    let status = response.status();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .with_context(|| format!("failed to read response: {}", url))?;
+
+    if !status.is_success() {
+        bail!("{} returned {}: {}", url, status, body.trim());
+    }
+    Ok(body)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub fn fetch(client: &Client, inputs_dir: &Path, year: u16, day: u8) -> Result<Fetched> {
+    let path = input::input_path(inputs_dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+
+    let dir = path.parent().unwrap_or(inputs_dir);
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, &path).with_context(|| format!("failed to write {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::mock::serve;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()))
+    }
+
+    fn client(base_url: &str, state_dir: &Path) -> Client {
+        let mut client = Client::new(base_url, "secret\n", state_dir);
+        client.throttle = Duration::ZERO;
+        client
+    }
+
+    #[test]
+    fn test_input_url() {
+        let client = Client::new("https://example.com/", "", Path::new("."));
+        assert_eq!(
+            client.input_url(2025, 3),
+            "https://example.com/2025/day/3/input"
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_cache() -> Result<()> {
+        let dir = temp_dir("cache");
+        let (base_url, requests) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = client(&base_url, &dir.join("state"));
+        let inputs_dir = dir.join("inputs");
+
+        let path = inputs_dir.join("2025/day03.txt");
+        assert_eq!(
+            fetch(&client, &inputs_dir, 2025, 3)?,
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path)?, "1\n2\n3\n");
+
+        let request = requests.recv()?.to_lowercase();
+        assert!(request.starts_with("get /2025/day/3/input "));
+        assert!(request.contains("cookie: session=secret\r\n"));
+
+        assert_eq!(fetch(&client, &inputs_dir, 2025, 3)?, Fetched::Cached(path));
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_error() -> Result<()> {
+        let dir = temp_dir("error");
+        let (base_url, _requests) = serve(vec![(400, "Please log in.")]);
+        let client = client(&base_url, &dir.join("state"));
+
+        let error = fetch(&client, &dir.join("inputs"), 2025, 1).unwrap_err();
+        assert!(format!("{:#}", error).contains("400"));
+        assert!(!dir.join("inputs/2025/day01.txt").exists());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_throttle() -> Result<()> {
+        let dir = temp_dir("throttle");
+        let mut client = Client::new("http://localhost", "", &dir);
+        client.throttle = Duration::from_millis(200);
+
+        client.wait_for_throttle()?;
+        let start = Instant::now();
+        client.wait_for_throttle()?;
+        assert!(start.elapsed() >= Duration::from_millis(150));
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
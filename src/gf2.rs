@@ -0,0 +1,308 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+// Walking the nullspace takes 2^dependencies steps and searching the column
+// space visits 2^rank states, so give up when both are past these.
+pub const MAX_DEPENDENCIES: usize = 24;
+pub const MAX_RANK: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyDependencies {
+    pub dependencies: usize,
+    pub rank: usize,
+}
+
+impl fmt::Display for TooManyDependencies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} dependent columns of rank {} is more than the {} or rank {} supported",
+            self.dependencies, self.rank, MAX_DEPENDENCIES, MAX_RANK
+        )
+    }
+}
+
+impl std::error::Error for TooManyDependencies {}
+
+// A set of column indices, used to remember which columns were combined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Columns(Vec<u64>);
+
+impl Columns {
+    fn empty(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn single(len: usize, column: usize) -> Self {
+        let mut columns = Self::empty(len);
+        columns.0[column / 64] |= 1 << (column % 64);
+        columns
+    }
+
+    fn toggle(&mut self, other: &Self) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word ^= other;
+        }
+    }
+
+    fn count(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    fn to_vec(&self, len: usize) -> Vec<bool> {
+        (0..len)
+            .map(|column| self.0[column / 64] & (1 << (column % 64)) != 0)
+            .collect()
+    }
+}
+
+// Bit `i` of `columns[j]` is the coefficient of `x_j` in equation `i`.
+pub struct System {
+    len: usize,
+    columns: Vec<u64>,
+    // Basis vectors with distinct leading bits, sorted by leading bit from
+    // the highest down, each with the columns that sum to it.
+    basis: Vec<(u64, Columns)>,
+    nullspace: Vec<Columns>,
+}
+
+impl System {
+    pub fn new(columns: &[u64]) -> Self {
+        let mut system = Self {
+            len: columns.len(),
+            columns: columns.to_vec(),
+            basis: Vec::new(),
+            nullspace: Vec::new(),
+        };
+
+        for (j, &column) in columns.iter().enumerate() {
+            let (vector, combination) = system.reduce(column, Columns::single(columns.len(), j));
+            if vector == 0 {
+                system.nullspace.push(combination);
+            } else {
+                let position = system
+                    .basis
+                    .partition_point(|(b, _)| b.leading_zeros() < vector.leading_zeros());
+                system.basis.insert(position, (vector, combination));
+            }
+        }
+
+        system
+    }
+
+    fn reduce(&self, mut vector: u64, mut combination: Columns) -> (u64, Columns) {
+        for (b, columns) in &self.basis {
+            let leading = 1 << (63 - b.leading_zeros());
+            if vector & leading != 0 {
+                vector ^= b;
+                combination.toggle(columns);
+            }
+        }
+        (vector, combination)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.basis.len()
+    }
+
+    pub fn solve(&self, target: u64) -> Option<Vec<bool>> {
+        self.particular(target).map(|x| x.to_vec(self.len))
+    }
+
+    fn particular(&self, target: u64) -> Option<Columns> {
+        let (rest, combination) = self.reduce(target, Columns::empty(self.len));
+        (rest == 0).then_some(combination)
+    }
+
+    pub fn nullspace(&self) -> Vec<Vec<bool>> {
+        self.nullspace
+            .iter()
+            .map(|columns| columns.to_vec(self.len))
+            .collect()
+    }
+
+    pub fn min_weight_solution(
+        &self,
+        target: u64,
+    ) -> Result<Option<Vec<bool>>, TooManyDependencies> {
+        let (dependencies, rank) = (self.nullspace.len(), self.rank());
+        let Some(x) = self.particular(target) else {
+            return Ok(None);
+        };
+
+        if rank <= MAX_RANK && (rank < dependencies || dependencies > MAX_DEPENDENCIES) {
+            Ok(Some(self.search_column_space(target)))
+        } else if dependencies <= MAX_DEPENDENCIES {
+            Ok(Some(self.walk_nullspace(x)))
+        } else {
+            Err(TooManyDependencies { dependencies, rank })
+        }
+    }
+
+    // Tries every combination of the nullspace basis in Gray code order, one
+    // toggle per step, and rebuilds the best one at the end rather than
+    // copying it each time.
+    fn walk_nullspace(&self, particular: Columns) -> Vec<bool> {
+        let mut x = particular.clone();
+        let (mut best, mut best_step) = (x.count(), 0_u32);
+        for step in 1..1_u32 << self.nullspace.len() {
+            x.toggle(&self.nullspace[step.trailing_zeros() as usize]);
+            let count = x.count();
+            if count < best {
+                (best, best_step) = (count, step);
+            }
+        }
+
+        let mut x = particular;
+        let gray = best_step ^ best_step >> 1;
+        for (i, columns) in self.nullspace.iter().enumerate() {
+            if gray & 1 << i != 0 {
+                x.toggle(columns);
+            }
+        }
+        x.to_vec(self.len)
+    }
+
+    // Coordinates of a vector in the span over the basis, one bit per basis
+    // vector.
+    fn coordinates(&self, mut vector: u64) -> usize {
+        let mut coordinates = 0;
+        for (i, (b, _)) in self.basis.iter().enumerate() {
+            if vector & 1 << (63 - b.leading_zeros()) != 0 {
+                vector ^= b;
+                coordinates |= 1 << i;
+            }
+        }
+        coordinates
+    }
+
+    // Breadth-first search over the 2^rank vectors the columns can reach, for
+    // systems with more dependent columns than the nullspace walk can handle.
+    // A shortest path never uses a column twice, so its length is the weight.
+    fn search_column_space(&self, target: u64) -> Vec<bool> {
+        let steps = self
+            .columns
+            .iter()
+            .map(|&column| self.coordinates(column))
+            .collect::<Vec<_>>();
+        let target = self.coordinates(target);
+
+        let mut reached_by = vec![None; 1 << self.rank()];
+        reached_by[0] = Some(usize::MAX);
+        let mut queue = VecDeque::from([0]);
+        while reached_by[target].is_none()
+            && let Some(state) = queue.pop_front()
+        {
+            for (j, &step) in steps.iter().enumerate() {
+                if reached_by[state ^ step].is_none() {
+                    reached_by[state ^ step] = Some(j);
+                    queue.push_back(state ^ step);
+                }
+            }
+        }
+
+        let mut x = vec![false; self.len];
+        let mut state = target;
+        while state != 0 {
+            let j = reached_by[state].expect("target is in the span");
+            x[j] = true;
+            state ^= steps[j];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(columns: &[u64], x: &[bool]) -> u64 {
+        columns
+            .iter()
+            .zip(x)
+            .filter(|&(_, &set)| set)
+            .fold(0, |sum, (column, _)| sum ^ column)
+    }
+
+    #[test]
+    fn test_solve_and_nullspace() {
+        let columns = [0b011, 0b110, 0b101, 0b100];
+        let system = System::new(&columns);
+        assert_eq!(system.rank(), 3);
+
+        let nullspace = system.nullspace();
+        assert_eq!(nullspace, vec![vec![true, true, true, false]]);
+        assert_eq!(apply(&columns, &nullspace[0]), 0);
+
+        let x = system.solve(0b111).unwrap();
+        assert_eq!(apply(&columns, &x), 0b111);
+        assert_eq!(System::new(&[0b011, 0b110]).solve(0b001), None);
+    }
+
+    #[test]
+    fn test_min_weight_solution() {
+        let columns = [0b0001, 0b0010, 0b0100, 0b1000, 0b1111];
+        let system = System::new(&columns);
+        assert_eq!(
+            system
+                .solve(0b1111)
+                .map(|x| x.iter().filter(|&&b| b).count()),
+            Some(4)
+        );
+        assert_eq!(
+            system.min_weight_solution(0b1111),
+            Ok(Some(vec![false, false, false, false, true]))
+        );
+        assert_eq!(system.min_weight_solution(0), Ok(Some(vec![false; 5])));
+    }
+
+    #[test]
+    fn test_many_columns_and_rows() {
+        let columns = (0..100)
+            .map(|j| 1_u64 << (j % 64) | 1 << 63)
+            .collect::<Vec<_>>();
+        let system = System::new(&columns);
+        assert_eq!(system.rank(), 64);
+        assert_eq!(system.nullspace().len(), 36);
+
+        let x = system.solve(u64::MAX).unwrap();
+        assert_eq!(apply(&columns, &x), u64::MAX);
+        assert_eq!(
+            system.min_weight_solution(u64::MAX),
+            Err(TooManyDependencies {
+                dependencies: 36,
+                rank: 64
+            })
+        );
+    }
+
+    #[test]
+    fn test_min_weight_solution_with_many_redundant_columns() {
+        // 30 buttons over 4 lights, so 26 are dependent but only 16 states
+        // are reachable.
+        let columns = (0..30).map(|j| 1_u64 << (j % 4)).collect::<Vec<_>>();
+        let system = System::new(&columns);
+        assert_eq!(system.nullspace().len(), 26);
+
+        let x = system.min_weight_solution(0b0101).unwrap().unwrap();
+        assert_eq!(apply(&columns, &x), 0b0101);
+        assert_eq!(x.iter().filter(|&&set| set).count(), 2);
+        assert_eq!(system.min_weight_solution(0), Ok(Some(vec![false; 30])));
+    }
+
+    #[test]
+    fn test_min_weight_solution_with_many_dependencies() {
+        // 48 single lights, then 16 buttons covering three lights each.
+        let columns = (0..48)
+            .map(|j| 1_u64 << j)
+            .chain((0..16).map(|k| 0b111 << (3 * k)))
+            .collect::<Vec<_>>();
+        let system = System::new(&columns);
+        assert_eq!(system.nullspace().len(), 16);
+
+        let target = (1 << 48) - 1;
+        let x = system.min_weight_solution(target).unwrap().unwrap();
+        assert_eq!(apply(&columns, &x), target);
+        assert_eq!(x, (0..64).map(|j| j >= 48).collect::<Vec<_>>());
+    }
+}
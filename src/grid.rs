@@ -0,0 +1,266 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::{Result, bail};
+
+use crate::parse::ParseError;
+
+pub type Point = (usize, usize);
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Self::from_lines(input.lines(), cell)
+    }
+
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in lines {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => bail!(ParseError::new(
+                        &line[i..i + c.len_utf8()],
+                        format!("invalid character {:?}", c)
+                    )),
+                }
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                Some(width) if width != row_width => bail!(ParseError::new(
+                    line,
+                    format!("expected {} cells, found {}", width, row_width)
+                )),
+                Some(_) => (),
+                None => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let (x, y) = point;
+        self.contains(point).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    // Row-major, so `(x, y)` is at `y * width + x`.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(point).then_some(point)
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.offset(point, direction))
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |direction| self.offset(point, direction))
+    }
+
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            text.extend(row.iter().map(&cell));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", point, width, height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn rolls(c: char) -> Option<bool> {
+        match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_render() -> Result<()> {
+        let grid = Grid::parse("..@\n@@.\n", rolls)?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 0)), Some(&true));
+        assert_eq!(grid.get((3, 0)), None);
+        assert!(grid[(1, 1)]);
+        assert_eq!(
+            grid.iter()
+                .filter(|&(_, &roll)| roll)
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>(),
+            vec![(2, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            grid.render(|&roll| if roll { '@' } else { '.' }),
+            "..@\n@@.\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "..@\n@x.\n";
+        let error = parse::locate(Grid::parse(input, rolls).unwrap_err(), input);
+        assert!(
+            error
+                .to_string()
+                .starts_with("invalid character 'x' at line 2, column 2")
+        );
+
+        let input = "..@\n@.\n";
+        let error = parse::locate(Grid::parse(input, rolls).unwrap_err(), input);
+        assert!(
+            error
+                .to_string()
+                .starts_with("expected 3 cells, found 2 at line 2")
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 7;
+        *grid.get_mut((0, 1)).unwrap() = 3;
+        assert!(grid.get_mut((2, 1)).is_none());
+        assert_eq!(grid.to_string(), "07\n30\n");
+    }
+}
@@ -0,0 +1,352 @@
+use crate::linalg::{GeneralSolution, Overflow, Rational};
+
+type Constraint = (Vec<Rational>, Rational);
+
+fn dot(a: &[Rational], b: &[Rational]) -> Result<Rational, Overflow> {
+    a.iter()
+        .zip(b)
+        .try_fold(Rational::ZERO, |sum, (&x, &y)| {
+            sum.checked_add(x.checked_mul(y)?)
+        })
+        .ok_or(Overflow)
+}
+
+// A simplex tableau whose last column holds the right-hand sides.
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn rhs(&self, row: usize) -> Rational {
+        *self.rows[row].last().unwrap_or(&Rational::ZERO)
+    }
+
+    fn pivot(&mut self, row: usize, col: usize) -> Result<(), Overflow> {
+        let pivot = self.rows[row][col];
+        for value in &mut self.rows[row] {
+            *value = value.checked_div(pivot).ok_or(Overflow)?;
+        }
+
+        let pivot_row = self.rows[row].clone();
+        for (r, other) in self.rows.iter_mut().enumerate() {
+            let factor = other[col];
+            if r == row || factor.is_zero() {
+                continue;
+            }
+            for (value, &p) in other.iter_mut().zip(&pivot_row) {
+                let scaled = factor.checked_mul(p).ok_or(Overflow)?;
+                *value = value.checked_sub(scaled).ok_or(Overflow)?;
+            }
+        }
+
+        self.basis[row] = col;
+        Ok(())
+    }
+
+    fn reduced_cost(&self, cost: &[Rational], col: usize) -> Result<Rational, Overflow> {
+        self.rows
+            .iter()
+            .zip(&self.basis)
+            .try_fold(cost[col], |reduced, (row, &basic)| {
+                reduced.checked_sub(cost[basic].checked_mul(row[col])?)
+            })
+            .ok_or(Overflow)
+    }
+
+    // Pivots until no column below `columns` can lower the cost, choosing the
+    // lowest eligible column and row each time (Bland's rule) so degenerate
+    // problems cannot cycle. Returns false if the cost is unbounded below.
+    fn minimize(&mut self, cost: &[Rational], columns: usize) -> Result<bool, Overflow> {
+        loop {
+            let mut entering = None;
+            for col in 0..columns {
+                if self.reduced_cost(cost, col)? < Rational::ZERO {
+                    entering = Some(col);
+                    break;
+                }
+            }
+            let Some(col) = entering else {
+                return Ok(true);
+            };
+
+            let mut leaving: Option<(usize, Rational)> = None;
+            for (r, row) in self.rows.iter().enumerate() {
+                if row[col] <= Rational::ZERO {
+                    continue;
+                }
+                let ratio = self.rhs(r).checked_div(row[col]).ok_or(Overflow)?;
+                match leaving {
+                    Some((best_row, best))
+                        if ratio > best
+                            || (ratio == best && self.basis[r] > self.basis[best_row]) => {}
+                    _ => leaving = Some((r, ratio)),
+                }
+            }
+            let Some((row, _)) = leaving else {
+                return Ok(false);
+            };
+
+            self.pivot(row, col)?;
+        }
+    }
+}
+
+// Two-phase simplex over `t >= 0` with `coeffs · t <= bound` constraints.
+// `None` if they are infeasible or leave the cost unbounded.
+fn solve_lp(
+    constraints: &[Constraint],
+    cost: &[Rational],
+) -> Result<Option<Vec<Rational>>, Overflow> {
+    let vars = cost.len();
+    let slacks = constraints.len();
+    let artificials = constraints
+        .iter()
+        .filter(|(_, bound)| *bound < Rational::ZERO)
+        .count();
+    let columns = vars + slacks + artificials;
+
+    let mut tableau = Tableau {
+        rows: Vec::with_capacity(slacks),
+        basis: Vec::with_capacity(slacks),
+    };
+    let mut artificial = vars + slacks;
+    for (i, (coeffs, bound)) in constraints.iter().enumerate() {
+        let mut row = vec![Rational::ZERO; columns + 1];
+        row[..vars].copy_from_slice(coeffs);
+        row[vars + i] = Rational::ONE;
+        row[columns] = *bound;
+
+        // A negative bound makes the slack start negative, so the row is
+        // flipped and an artificial variable supplies the starting basis.
+        if *bound < Rational::ZERO {
+            for value in &mut row {
+                *value = value.checked_neg().ok_or(Overflow)?;
+            }
+            row[artificial] = Rational::ONE;
+            tableau.basis.push(artificial);
+            artificial += 1;
+        } else {
+            tableau.basis.push(vars + i);
+        }
+        tableau.rows.push(row);
+    }
+
+    if artificials > 0 {
+        let mut phase_one = vec![Rational::ZERO; columns];
+        phase_one[vars + slacks..].fill(Rational::ONE);
+        tableau.minimize(&phase_one, columns)?;
+
+        let infeasible = (0..slacks)
+            .any(|row| tableau.basis[row] >= vars + slacks && !tableau.rhs(row).is_zero());
+        if infeasible {
+            return Ok(None);
+        }
+
+        for row in 0..slacks {
+            if tableau.basis[row] < vars + slacks {
+                continue;
+            }
+            // Rows where every real coefficient is zero are redundant and
+            // keep their artificial variable at zero.
+            if let Some(col) = (0..vars + slacks).find(|&c| !tableau.rows[row][c].is_zero()) {
+                tableau.pivot(row, col)?;
+            }
+        }
+    }
+
+    let mut phase_two = vec![Rational::ZERO; columns];
+    phase_two[..vars].copy_from_slice(cost);
+    if !tableau.minimize(&phase_two, vars + slacks)? {
+        return Ok(None);
+    }
+
+    let mut solution = vec![Rational::ZERO; vars];
+    for (row, &basic) in tableau.basis.iter().enumerate() {
+        if basic < vars {
+            solution[basic] = tableau.rhs(row);
+        }
+    }
+    Ok(Some(solution))
+}
+
+struct Search<'a> {
+    solution: &'a GeneralSolution,
+    // The objective in terms of the free variables: `constant + cost · t`.
+    constant: Rational,
+    cost: Vec<Rational>,
+    integral: bool,
+    best: Option<(Rational, Vec<i64>)>,
+}
+
+impl Search<'_> {
+    // Each variable `x_j = particular_j + nullspace[..][j] · t` must stay
+    // within `lower_j..=upper_j`.
+    fn constraints(
+        &self,
+        lower: &[i64],
+        upper: &[i64],
+    ) -> Result<Option<Vec<Constraint>>, Overflow> {
+        let mut constraints = Vec::new();
+        for (j, (&low, &high)) in lower.iter().zip(upper).enumerate() {
+            let constant = self.solution.particular[j];
+            let coeffs = self
+                .solution
+                .nullspace
+                .iter()
+                .map(|basis| basis[j])
+                .collect::<Vec<_>>();
+
+            if coeffs.iter().all(|c| c.is_zero()) {
+                if constant < Rational::from(low) || constant > Rational::from(high) {
+                    return Ok(None);
+                }
+                continue;
+            }
+
+            let to_upper = Rational::from(high).checked_sub(constant).ok_or(Overflow)?;
+            let from_lower = constant.checked_sub(Rational::from(low)).ok_or(Overflow)?;
+            let negated = coeffs
+                .iter()
+                .map(|c| c.checked_neg().ok_or(Overflow))
+                .collect::<Result<Vec<_>, _>>()?;
+            constraints.push((coeffs, to_upper));
+            constraints.push((negated, from_lower));
+        }
+        Ok(Some(constraints))
+    }
+
+    fn branch(&mut self, lower: Vec<i64>, upper: Vec<i64>) -> Result<(), Overflow> {
+        if lower.iter().zip(&upper).any(|(low, high)| low > high) {
+            return Ok(());
+        }
+        let Some(constraints) = self.constraints(&lower, &upper)? else {
+            return Ok(());
+        };
+        let Some(t) = solve_lp(&constraints, &self.cost)? else {
+            return Ok(());
+        };
+
+        let value = self
+            .constant
+            .checked_add(dot(&self.cost, &t)?)
+            .ok_or(Overflow)?;
+        let bound = if self.integral {
+            Rational::from(value.ceil())
+        } else {
+            value
+        };
+        if let Some((best, _)) = &self.best
+            && bound >= *best
+        {
+            return Ok(());
+        }
+
+        let x = self.solution.evaluate(&t)?;
+        match x.iter().position(|v| !v.is_integer()) {
+            Some(j) => {
+                let mut below = upper.clone();
+                below[j] = x[j].floor();
+                self.branch(lower.clone(), below)?;
+
+                let mut above = lower;
+                above[j] = x[j].ceil();
+                self.branch(above, upper)?;
+            }
+            None => {
+                self.best = Some((value, x.iter().map(|v| v.numer()).collect()));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Branch and bound over the free variables, using LP relaxations as lower
+// bounds.
+pub fn minimize(
+    solution: &GeneralSolution,
+    objective: &[Rational],
+    upper: &[i64],
+) -> Result<Option<Vec<i64>>, Overflow> {
+    assert_eq!(objective.len(), solution.particular.len());
+    assert_eq!(upper.len(), solution.particular.len());
+
+    let cost = solution
+        .nullspace
+        .iter()
+        .map(|basis| dot(objective, basis))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut search = Search {
+        solution,
+        constant: dot(objective, &solution.particular)?,
+        cost,
+        integral: objective.iter().all(|c| c.is_integer()),
+        best: None,
+    };
+    search.branch(vec![0; upper.len()], upper.to_vec())?;
+
+    Ok(search.best.map(|(_, x)| x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::Matrix;
+
+    fn integers(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::from(v)).collect()
+    }
+
+    fn system(rows: &[&[i64]], rhs: &[i64]) -> GeneralSolution {
+        let matrix = Matrix::from_rows(rows.iter().map(|row| integers(row)).collect());
+        matrix.solve(&integers(rhs)).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_lp() -> Result<(), Overflow> {
+        // minimise -x - y with x + 2y <= 4, 3x + y <= 6: optimum at (8/5, 6/5).
+        let constraints = [
+            (integers(&[1, 2]), Rational::from(4)),
+            (integers(&[3, 1]), Rational::from(6)),
+        ];
+        let t = solve_lp(&constraints, &integers(&[-1, -1]))?.unwrap();
+        assert_eq!(t, vec![Rational::new(8, 5), Rational::new(6, 5)]);
+
+        // x >= 2 and x <= 1 cannot both hold.
+        let constraints = [
+            (integers(&[-1]), Rational::from(-2)),
+            (integers(&[1]), Rational::from(1)),
+        ];
+        assert_eq!(solve_lp(&constraints, &integers(&[1]))?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_minimize_branches_on_fractional_relaxation() -> Result<(), Overflow> {
+        // 2a + 2b + 3c = 7 has the fractional relaxation c = 7/3; the best
+        // integer point is a + b = 2, c = 1.
+        let solution = system(&[&[2, 2, 3]], &[7]);
+        let x = minimize(&solution, &integers(&[1, 1, 1]), &[10, 10, 10])?.unwrap();
+        assert_eq!(x[0] + x[1], 2);
+        assert_eq!(x[2], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_minimize_respects_bounds() -> Result<(), Overflow> {
+        let solution = system(&[&[1, 1, 0], &[0, 1, 1]], &[3, 5]);
+        assert_eq!(
+            minimize(&solution, &integers(&[1, 1, 1]), &[5, 5, 5])?,
+            Some(vec![0, 3, 2])
+        );
+        assert_eq!(
+            minimize(&solution, &integers(&[1, 1, 1]), &[5, 2, 5])?,
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(
+            minimize(&solution, &integers(&[1, 1, 1]), &[0, 5, 1])?,
+            None
+        );
+        Ok(())
+    }
+}
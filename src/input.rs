@@ -0,0 +1,68 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+pub const STDIN: &str = "-";
+
+pub fn input_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+pub fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .context("failed to read input from stdin")?;
+    Ok(input)
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    if path == Path::new(STDIN) {
+        return read_stdin();
+    }
+
+    fs::read_to_string(path).with_context(|| format!("failed to read input: {}", path.display()))
+}
+
+pub fn read_input(inputs_dir: &Path, year: u16, day: u8) -> Result<String> {
+    read_file(&input_path(inputs_dir, year, day))
+}
+
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let path = input_path(Path::new("inputs"), 2025, 7);
+        assert_eq!(path, Path::new("inputs/2025/day07.txt"));
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_read_input_missing() {
+        let error = read_input(Path::new("does-not-exist"), 2025, 1).unwrap_err();
+        assert!(error.to_string().contains("does-not-exist/2025/day01.txt"));
+    }
+}
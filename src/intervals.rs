@@ -0,0 +1,244 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+pub trait Integer: Copy + Ord {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn successor(self) -> Option<Self> {
+        self.checked_add(Self::ONE)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        self.checked_sub(Self::ONE)
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+// Stored as sorted ranges that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Ranges that overlap or touch the new one are folded into it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.successor().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.successor().is_none_or(|next| s <= next));
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for &(start, end) in &other.ranges {
+            self.insert(start..=end);
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = Some(T::MIN);
+        for &(start, end) in &self.ranges {
+            if let Some(gap_start) = next
+                && gap_start < start
+                && let Some(gap_end) = start.predecessor()
+            {
+                ranges.push((gap_start, gap_end));
+            }
+            next = end.successor();
+        }
+        if let Some(gap_start) = next {
+            ranges.push((gap_start, T::MAX));
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    // `None` if the count does not fit in `T`.
+    pub fn len(&self) -> Option<T> {
+        self.ranges
+            .iter()
+            .try_fold(T::ZERO, |total, &(start, end)| {
+                end.checked_sub(start)?.successor()?.checked_add(total)
+            })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer + fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges<T: Integer>(set: &RangeSet<T>) -> Vec<RangeInclusive<T>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent() {
+        let mut set = RangeSet::new();
+        set.insert(10..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        set.insert(12..=18);
+        set.insert(6..=6);
+        set.insert(RangeInclusive::new(9, 1));
+        assert_eq!(ranges(&set), vec![3..=6, 10..=20]);
+
+        set.insert(7..=9);
+        assert_eq!(ranges(&set), vec![3..=20]);
+        assert_eq!(set.len(), Some(18));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = RangeSet::from_iter([3..=5, 10..=14, 16..=20, 12..=18]);
+        assert!(!set.contains(1));
+        assert!(set.contains(5));
+        assert!(!set.contains(8));
+        assert!(set.contains(11));
+        assert!(set.contains(17));
+        assert!(!set.contains(32));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = RangeSet::from_iter([0..=10, 20..=30]);
+        let b = RangeSet::from_iter([5..=25, 40..=50]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![0..=30, 40..=50]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19, 40..=50]);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut set = RangeSet::from_iter([u64::MAX - 1..=u64::MAX, 0..=0]);
+        assert_eq!(set.len(), Some(3));
+        assert_eq!(ranges(&set.complement()), vec![1..=u64::MAX - 2]);
+
+        set.insert(1..=u64::MAX - 2);
+        assert_eq!(ranges(&set), vec![0..=u64::MAX]);
+        assert_eq!(set.len(), None);
+        assert!(set.complement().is_empty());
+    }
+}
@@ -1,14 +1,25 @@
-#![cfg_attr(test, feature(test))]
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod config;
+pub mod dsu;
+pub mod examples;
+pub mod fetch;
+pub mod gf2;
+pub mod grid;
+pub mod ilp;
+pub mod input;
+pub mod intervals;
+pub mod linalg;
+#[cfg(test)]
+mod mock;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod watch;
+pub mod y2025;
@@ -0,0 +1,578 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rational arithmetic overflowed")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+// Kept in lowest terms with a positive denominator. The operators panic on
+// overflow; the `checked_` methods return `None` instead.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i64,
+    denom: i64,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numer: 0, denom: 1 };
+    pub const ONE: Self = Self { numer: 1, denom: 1 };
+
+    fn reduce(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        let sign = if (numer < 0) != (denom < 0) { -1 } else { 1 };
+        let (numer, denom) = (numer.unsigned_abs(), denom.unsigned_abs());
+        let divisor = gcd(numer, denom);
+        let numer = i128::try_from(numer / divisor).ok()? * sign;
+        Some(Self {
+            numer: numer.try_into().ok()?,
+            denom: (denom / divisor).try_into().ok()?,
+        })
+    }
+
+    pub fn checked_new(numer: i64, denom: i64) -> Option<Self> {
+        Self::reduce(numer.into(), denom.into())
+    }
+
+    pub fn new(numer: i64, denom: i64) -> Self {
+        assert!(denom != 0, "rational with a zero denominator");
+        Self::checked_new(numer, denom).expect("rational arithmetic overflowed")
+    }
+
+    pub fn numer(self) -> i64 {
+        self.numer
+    }
+
+    pub fn denom(self) -> i64 {
+        self.denom
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denom == 1
+    }
+
+    pub fn to_integer(self) -> Option<i64> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn floor(self) -> i64 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    pub fn ceil(self) -> i64 {
+        let floor = self.floor();
+        if self.is_integer() { floor } else { floor + 1 }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (i128::from(self.denom), i128::from(rhs.denom));
+        Self::reduce(
+            i128::from(self.numer) * b + i128::from(rhs.numer) * a,
+            a * b,
+        )
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (i128::from(self.denom), i128::from(rhs.denom));
+        Self::reduce(
+            i128::from(self.numer) * b - i128::from(rhs.numer) * a,
+            a * b,
+        )
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::reduce(
+            i128::from(self.numer) * i128::from(rhs.numer),
+            i128::from(self.denom) * i128::from(rhs.denom),
+        )
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::reduce(
+            i128::from(self.numer) * i128::from(rhs.denom),
+            i128::from(self.denom) * i128::from(rhs.numer),
+        )
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .expect("rational arithmetic overflowed")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("rational arithmetic overflowed")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("rational arithmetic overflowed")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "rational division by zero");
+        self.checked_div(rhs)
+            .expect("rational arithmetic overflowed")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("rational arithmetic overflowed")
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer as i128 * other.denom as i128).cmp(&(other.numer as i128 * self.denom as i128))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            f.pad(&self.numer.to_string())
+        } else {
+            f.pad(&format!("{}/{}", self.numer, self.denom))
+        }
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    cells: Vec<Rational>,
+}
+
+// `particular` plus any combination of the `nullspace` vectors, one per free
+// column.
+#[derive(Debug, Clone)]
+pub struct GeneralSolution {
+    pub particular: Vec<Rational>,
+    pub pivot_columns: Vec<usize>,
+    pub free_columns: Vec<usize>,
+    pub nullspace: Vec<Vec<Rational>>,
+}
+
+impl GeneralSolution {
+    pub fn value(&self, column: usize, free_values: &[Rational]) -> Result<Rational, Overflow> {
+        self.nullspace
+            .iter()
+            .zip(free_values)
+            .try_fold(self.particular[column], |value, (basis, &t)| {
+                value.checked_add(basis[column].checked_mul(t)?)
+            })
+            .ok_or(Overflow)
+    }
+
+    pub fn evaluate(&self, free_values: &[Rational]) -> Result<Vec<Rational>, Overflow> {
+        (0..self.particular.len())
+            .map(|column| self.value(column, free_values))
+            .collect()
+    }
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![Rational::ZERO; rows * cols],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<Rational>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "matrix rows differ in length"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[Rational] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.cells.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+
+    pub fn mul_vector(&self, vector: &[Rational]) -> Result<Vec<Rational>, Overflow> {
+        assert_eq!(vector.len(), self.cols);
+        (0..self.rows)
+            .map(|row| {
+                self.row(row)
+                    .iter()
+                    .zip(vector)
+                    .try_fold(Rational::ZERO, |sum, (&a, &b)| {
+                        sum.checked_add(a.checked_mul(b)?)
+                    })
+                    .ok_or(Overflow)
+            })
+            .collect()
+    }
+
+    // Gauss-Jordan elimination choosing pivots only from the first `limit`
+    // columns, so an augmented column is carried along but never pivoted on.
+    fn reduce_columns(&mut self, limit: usize) -> Result<Vec<usize>, Overflow> {
+        let mut pivots = Vec::new();
+        for col in 0..limit {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+            let Some(found) = (row..self.rows).find(|&r| !self[(r, col)].is_zero()) else {
+                continue;
+            };
+            self.swap_rows(row, found);
+
+            let pivot = self[(row, col)];
+            for c in col..self.cols {
+                self[(row, c)] = self[(row, c)].checked_div(pivot).ok_or(Overflow)?;
+            }
+
+            for r in 0..self.rows {
+                let factor = self[(r, col)];
+                if r == row || factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    let scaled = factor.checked_mul(self[(row, c)]).ok_or(Overflow)?;
+                    self[(r, c)] = self[(r, c)].checked_sub(scaled).ok_or(Overflow)?;
+                }
+            }
+
+            pivots.push(col);
+        }
+        Ok(pivots)
+    }
+
+    // Returns the pivot columns.
+    pub fn reduce(&mut self) -> Result<Vec<usize>, Overflow> {
+        self.reduce_columns(self.cols)
+    }
+
+    pub fn rref(&self) -> Result<Self, Overflow> {
+        let mut matrix = self.clone();
+        matrix.reduce()?;
+        Ok(matrix)
+    }
+
+    pub fn pivot_columns(&self) -> Result<Vec<usize>, Overflow> {
+        self.clone().reduce()
+    }
+
+    pub fn free_columns(&self) -> Result<Vec<usize>, Overflow> {
+        let pivots = self.pivot_columns()?;
+        Ok((0..self.cols).filter(|c| !pivots.contains(c)).collect())
+    }
+
+    pub fn rank(&self) -> Result<usize, Overflow> {
+        Ok(self.pivot_columns()?.len())
+    }
+
+    pub fn nullspace(&self) -> Result<Vec<Vec<Rational>>, Overflow> {
+        let mut reduced = self.clone();
+        let pivots = reduced.reduce()?;
+        reduced.nullspace_of_reduced(&pivots, self.cols)
+    }
+
+    fn nullspace_of_reduced(
+        &self,
+        pivots: &[usize],
+        cols: usize,
+    ) -> Result<Vec<Vec<Rational>>, Overflow> {
+        (0..cols)
+            .filter(|c| !pivots.contains(c))
+            .map(|free| {
+                let mut basis = vec![Rational::ZERO; cols];
+                basis[free] = Rational::ONE;
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    basis[pivot] = self[(row, free)].checked_neg().ok_or(Overflow)?;
+                }
+                Ok(basis)
+            })
+            .collect()
+    }
+
+    // `None` if the system is inconsistent.
+    pub fn solve(&self, rhs: &[Rational]) -> Result<Option<GeneralSolution>, Overflow> {
+        assert_eq!(rhs.len(), self.rows);
+        let mut augmented = Self::zeros(self.rows, self.cols + 1);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                augmented[(row, col)] = self[(row, col)];
+            }
+            augmented[(row, self.cols)] = rhs[row];
+        }
+
+        let pivots = augmented.reduce_columns(self.cols)?;
+        if (pivots.len()..self.rows).any(|row| !augmented[(row, self.cols)].is_zero()) {
+            return Ok(None);
+        }
+
+        let mut particular = vec![Rational::ZERO; self.cols];
+        for (row, &pivot) in pivots.iter().enumerate() {
+            particular[pivot] = augmented[(row, self.cols)];
+        }
+
+        Ok(Some(GeneralSolution {
+            particular,
+            free_columns: (0..self.cols).filter(|c| !pivots.contains(c)).collect(),
+            nullspace: augmented.nullspace_of_reduced(&pivots, self.cols)?,
+            pivot_columns: pivots,
+        }))
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Rational;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Rational {
+        assert!(row < self.rows && col < self.cols);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Rational {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .cells
+            .iter()
+            .map(|cell| cell.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for row in 0..self.rows {
+            let cells = self
+                .row(row)
+                .iter()
+                .map(|cell| format!("{:>width$}", cell))
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(numer: i64, denom: i64) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    fn matrix(rows: &[&[i64]]) -> Matrix {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().map(|&v| Rational::from(v)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(r(2, -4).denom(), 2);
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(3, 4), r(1, 2));
+        assert_eq!(r(2, 3) / r(-4, 3), r(-1, 2));
+        assert_eq!(-r(3, 5), r(-3, 5));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(7, 3).to_integer(), None);
+        assert_eq!((r(7, 3).floor(), r(7, 3).ceil()), (2, 3));
+        assert_eq!((r(-7, 3).floor(), r(-7, 3).ceil()), (-3, -2));
+        assert_eq!((r(-6, 3).floor(), r(-6, 3).ceil()), (-2, -2));
+        assert_eq!(r(7, 3).to_string(), "7/3");
+        assert_eq!(format!("{:>4}", r(-4, 2)), "  -2");
+    }
+
+    #[test]
+    fn test_rational_ordering() {
+        let mut values = vec![r(1, 2), r(-3, 2), r(1, 3), Rational::ZERO, r(5, 1)];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![r(-3, 2), Rational::ZERO, r(1, 3), r(1, 2), r(5, 1)]
+        );
+        assert!(r(-1, 3) > r(-1, 2));
+    }
+
+    #[test]
+    fn test_rational_overflow() {
+        let big = Rational::from(i64::MAX);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_mul(Rational::from(2)), None);
+        assert_eq!(Rational::from(i64::MIN).checked_neg(), None);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+        assert_eq!(Rational::ONE.checked_div(Rational::ZERO), None);
+
+        // Intermediates may exceed i64 as long as the reduced result fits.
+        assert_eq!(big.checked_mul(r(2, 4)).unwrap(), r(i64::MAX, 2));
+        assert_eq!(
+            r(i64::MAX, 2).checked_add(r(1, 2)),
+            Some(Rational::from(1 << 62))
+        );
+        assert_eq!(
+            Rational::checked_new(i64::MIN, i64::MIN),
+            Some(Rational::ONE)
+        );
+        assert!(r(i64::MAX, 2) > r(i64::MAX - 1, 2));
+    }
+
+    #[test]
+    fn test_rref_rank_and_columns() -> Result<(), Overflow> {
+        let m = matrix(&[&[1, 2, 1, 0], &[2, 4, 0, 2], &[3, 6, 1, 2]]);
+        assert_eq!(
+            m.rref()?,
+            matrix(&[&[1, 2, 0, 1], &[0, 0, 1, -1], &[0, 0, 0, 0]])
+        );
+        assert_eq!(m.rank()?, 2);
+        assert_eq!(m.pivot_columns()?, vec![0, 2]);
+        assert_eq!(m.free_columns()?, vec![1, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nullspace() -> Result<(), Overflow> {
+        let m = matrix(&[&[1, 2, 1, 0], &[2, 4, 0, 2], &[3, 6, 1, 2]]);
+        let nullspace = m.nullspace()?;
+        assert_eq!(nullspace.len(), 2);
+        for basis in &nullspace {
+            assert!(m.mul_vector(basis)?.iter().all(|v| v.is_zero()));
+        }
+        assert!(matrix(&[&[1, 0], &[0, 1]]).nullspace()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), Overflow> {
+        let m = matrix(&[&[1, 1, 0], &[0, 1, 1]]);
+        let rhs = [Rational::from(3), Rational::from(5)];
+        let solution = m.solve(&rhs)?.unwrap();
+        assert_eq!(solution.pivot_columns, vec![0, 1]);
+        assert_eq!(solution.free_columns, vec![2]);
+
+        let x = solution.evaluate(&[Rational::from(2)])?;
+        assert_eq!(
+            x,
+            vec![Rational::ZERO, Rational::from(3), Rational::from(2)]
+        );
+        assert_eq!(m.mul_vector(&x)?, rhs);
+
+        let inconsistent = matrix(&[&[1, 1], &[2, 2]]);
+        assert!(
+            inconsistent
+                .solve(&[Rational::ONE, Rational::ONE])?
+                .is_none()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_elimination_overflow() {
+        let m = matrix(&[&[i64::MAX, 1], &[1, i64::MAX]]);
+        assert_eq!(m.rank(), Err(Overflow));
+        assert_eq!(
+            m.solve(&[Rational::ONE, Rational::ONE]).err(),
+            Some(Overflow)
+        );
+    }
+}
@@ -1,80 +1,584 @@
-#![cfg_attr(test, feature(test))]
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-use anyhow::{Result, bail};
-use clap::Parser;
+use anyhow::{Context, Result, bail};
+use aoc::answers::{self, Answers, Verdict};
+use aoc::baseline::{self, Baseline};
+use aoc::config::{self, Config};
+use aoc::examples::{self, Examples};
+use aoc::fetch::{self, Client, Fetched};
+use aoc::registry::Calendar;
+use aoc::runner::{self, Outcome};
+use aoc::solution::Solution;
+use aoc::submit::{self, Judgement, Ledger, Response};
+use aoc::watch::{self, Watcher};
+use aoc::{bench, input, registry, report, scaffold};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
-#[command(about = "Advent of Code 2025")]
+#[command(about = "Advent of Code")]
 struct Args {
-    /// Day (1-12)
+    /// Puzzle year; commands that need a single year default to the latest one
+    #[arg(long, env = "AOC_YEAR", global = true)]
+    year: Option<u16>,
+    /// Directory containing the YYYY/dayNN.txt input files
+    #[arg(long, value_name = "DIR", env = "AOC_INPUTS_DIR", default_value = input::DEFAULT_INPUTS_DIR, global = true)]
+    inputs_dir: PathBuf,
+    /// Directory containing the YYYY/dayNN.toml example files
+    #[arg(long, value_name = "DIR", default_value = examples::DEFAULT_EXAMPLES_DIR, global = true)]
+    examples_dir: PathBuf,
+    /// Config file with the session token and base URL
+    #[arg(long, value_name = "PATH", env = "AOC_CONFIG", default_value = config::DEFAULT_CONFIG_FILE, global = true)]
+    config: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day and part
+    Run {
+        /// Day
+        day: u8,
+        /// Part (1-2)
+        part: u8,
+        /// Input file to solve, or "-" to read from stdin
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Solve the puzzle's examples and check them against their answers
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Run every registered day and part of the year, or of every year, and print a timing table
+    All {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Benchmark every solution, or only the given day and part
+    Bench {
+        /// Day
+        day: Option<u8>,
+        /// Part (1-2)
+        part: Option<u8>,
+        /// Warmup time per solution in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 300)]
+        warmup: u64,
+        /// Measurement time per solution in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 1000)]
+        measurement: u64,
+        /// Maximum number of samples per solution
+        #[arg(long, default_value_t = 50)]
+        samples: usize,
+        /// Save the results as a named baseline
+        #[arg(long, value_name = "NAME")]
+        save: Option<String>,
+        /// Compare the results against a named baseline
+        #[arg(long, value_name = "NAME")]
+        compare: Option<String>,
+        /// Percentage change in median time treated as noise
+        #[arg(long, value_name = "PERCENT", default_value_t = baseline::DEFAULT_THRESHOLD)]
+        threshold: f64,
+        /// Directory containing saved baselines
+        #[arg(long, value_name = "DIR", default_value = baseline::DEFAULT_BASELINES_DIR)]
+        baselines_dir: PathBuf,
+    },
+    /// Check every solution of the year, or of every year, against the known answers
+    Verify {
+        /// Answers file keyed by input hash, day and part
+        #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+    /// Download the puzzle input for a day unless it is already cached
+    Fetch {
+        /// Day
+        day: u8,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Submit the computed answer for a day and part
+    Submit {
+        /// Day
+        day: u8,
+        /// Part (1-2)
+        part: u8,
+        #[command(flatten)]
+        server: ServerArgs,
+        /// Ledger of previous guesses used to refuse known-wrong answers
+        #[arg(long, value_name = "PATH", default_value = submit::DEFAULT_LEDGER_FILE)]
+        ledger: PathBuf,
+    },
+    /// Re-run a day and part whenever its sources or input change
+    Watch {
+        /// Day
+        day: u8,
+        /// Part (1-2)
+        part: u8,
+        /// Polling interval in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = watch::DEFAULT_INTERVAL.as_millis() as u64)]
+        interval: u64,
+        /// Source directory containing the year modules
+        #[arg(long, value_name = "DIR", default_value = scaffold::DEFAULT_SRC_DIR)]
+        src_dir: PathBuf,
+    },
+    /// Generate the module skeleton for a new day and register it
+    New {
+        /// Day
+        day: u8,
+        /// Puzzle title, defaults to "Day N"
+        #[arg(long)]
+        title: Option<String>,
+        /// Source directory containing lib.rs and registry.rs
+        #[arg(long, value_name = "DIR", default_value = scaffold::DEFAULT_SRC_DIR)]
+        src_dir: PathBuf,
+        /// Fuzz crate to add a target to, skipped if it has no Cargo.toml
+        #[arg(long, value_name = "DIR", default_value = scaffold::DEFAULT_FUZZ_DIR)]
+        fuzz_dir: PathBuf,
+    },
+}
+
+#[derive(clap::Args)]
+struct ServerArgs {
+    /// Session cookie, read from the config file if not given
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Base URL of the Advent of Code server
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL")]
+    base_url: Option<String>,
+}
+
+fn print_outcomes(outcomes: &[Outcome], format: Format) -> Result<()> {
+    match format {
+        Format::Text => println!("{}", report::table(outcomes)),
+        Format::Json => println!("{}", report::json(outcomes)?),
+        Format::Csv => println!("{}", report::csv(outcomes)),
+    }
+
+    Ok(())
+}
+
+fn calendar(year: Option<u16>) -> Result<&'static Calendar> {
+    match year {
+        Some(year) => {
+            registry::calendar(year).context(format!("no solutions registered for {}", year))
+        }
+        None => Ok(registry::latest()),
+    }
+}
+
+fn solution(year: Option<u16>, day: u8, part: u8) -> Result<&'static dyn Solution> {
+    let calendar = calendar(year)?;
+    registry::check_day(calendar.year, day)?;
+    if !(1..=2).contains(&part) {
+        bail!("part must be 1 or 2");
+    }
+
+    registry::find(calendar.year, day, part).context(format!(
+        "no solution registered for {} day {} part {}",
+        calendar.year, day, part
+    ))
+}
+
+fn run(
+    year: Option<u16>,
     day: u8,
-    /// Part (1-2)
     part: u8,
+    input: Option<&Path>,
+    inputs_dir: &Path,
+    format: Format,
+) -> Result<()> {
+    let solution = solution(year, day, part)?;
+
+    let input = match input {
+        Some(path) => input::read_file(path)?,
+        None => input::read_input(inputs_dir, solution.year(), day)?,
+    };
+
+    let outcome = runner::run(solution, &input);
+
+    if let Format::Text = format {
+        println!("{}", outcome.answer?);
+        return Ok(());
+    }
+
+    print_outcomes(std::slice::from_ref(&outcome), format)?;
+
+    outcome.answer.map(|_| ())
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
+fn run_examples(
+    year: Option<u16>,
+    day: u8,
+    part: u8,
+    examples_dir: &Path,
+    format: Format,
+) -> Result<()> {
+    let solution = solution(year, day, part)?;
+    let examples = Examples::load(examples_dir, solution.year(), day)?;
 
-    if args.day < 1 || args.day > 12 {
-        bail!("day must be between 1 and 12");
+    let (outcomes, expected): (Vec<_>, Vec<_>) = examples
+        .for_part(part)
+        .map(|(example, expected)| (runner::run(solution, &example.input), expected))
+        .unzip();
+    if outcomes.is_empty() {
+        bail!("no examples with an answer for part {}", part);
     }
-    if args.part < 1 || args.part > 2 {
-        bail!("part must be 1 or 2");
+
+    if let Format::Text = format {
+        for (index, (outcome, expected)) in outcomes.iter().zip(&expected).enumerate() {
+            let (answer, status) = match &outcome.answer {
+                Ok(answer) if answer == expected => (answer.to_string(), "ok"),
+                Ok(answer) => (answer.to_string(), "WRONG"),
+                Err(error) => (format!("error: {:#}", error), "FAILED"),
+            };
+            println!(
+                "example {}: {} (expected {}) {}",
+                index + 1,
+                answer,
+                expected,
+                status
+            );
+        }
+    } else {
+        print_outcomes(&outcomes, format)?;
     }
 
-    let result = match (args.day, args.part) {
-        (1, 1) => day01::part1::run(include_str!("../inputs/day01.txt"))?,
-        (1, 2) => day01::part2::run(include_str!("../inputs/day01.txt"))?,
-        (2, 1) => day02::part1::run(include_str!("../inputs/day02.txt"))?,
-        (2, 2) => day02::part2::run(include_str!("../inputs/day02.txt"))?,
-        (3, 1) => day03::part1::run(include_str!("../inputs/day03.txt"))?,
-        (3, 2) => day03::part2::run(include_str!("../inputs/day03.txt"))?,
-        (4, 1) => day04::part1::run(include_str!("../inputs/day04.txt"))?,
-        (4, 2) => day04::part2::run(include_str!("../inputs/day04.txt"))?,
-        (5, 1) => day05::part1::run(include_str!("../inputs/day05.txt"))?,
-        (5, 2) => day05::part2::run(include_str!("../inputs/day05.txt"))?,
-        (6, 1) => day06::part1::run(include_str!("../inputs/day06.txt"))?,
-        (6, 2) => day06::part2::run(include_str!("../inputs/day06.txt"))?,
-        (7, 1) => day07::part1::run(include_str!("../inputs/day07.txt"))?,
-        (7, 2) => day07::part2::run(include_str!("../inputs/day07.txt"))?,
-        (8, 1) => day08::part1::run(include_str!("../inputs/day08.txt"))?,
-        (8, 2) => day08::part2::run(include_str!("../inputs/day08.txt"))?,
-        (9, 1) => day09::part1::run(include_str!("../inputs/day09.txt"))?,
-        (9, 2) => day09::part2::run(include_str!("../inputs/day09.txt"))?,
-        (10, 1) => day10::part1::run(include_str!("../inputs/day10.txt"))?,
-        (10, 2) => day10::part2::run(include_str!("../inputs/day10.txt"))?,
-        (11, 1) => day11::part1::run(include_str!("../inputs/day11.txt"))?,
-        (11, 2) => day11::part2::run(include_str!("../inputs/day11.txt"))?,
-        (12, 1) => day12::part1::run(include_str!("../inputs/day12.txt"))?,
-        (12, 2) => day12::part2::run(include_str!("../inputs/day12.txt"))?,
-        _ => unreachable!(),
+    let failed = outcomes
+        .iter()
+        .zip(&expected)
+        .filter(|(outcome, expected)| {
+            !outcome
+                .answer
+                .as_ref()
+                .is_ok_and(|answer| answer == *expected)
+        })
+        .count();
+    if failed > 0 {
+        bail!("{} of {} examples failed", failed, outcomes.len());
+    }
+
+    Ok(())
+}
+
+fn all(year: Option<u16>, inputs_dir: &Path, format: Format) -> Result<()> {
+    if let Some(year) = year {
+        calendar(Some(year))?;
+    }
+    let outcomes = runner::run_all(inputs_dir, year);
+
+    print_outcomes(&outcomes, format)?;
+
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.answer.is_err())
+        .count();
+    if failed > 0 {
+        bail!("{} of {} solutions failed", failed, outcomes.len());
+    }
+
+    Ok(())
+}
+
+struct BaselineArgs {
+    save: Option<String>,
+    compare: Option<String>,
+    threshold: f64,
+    dir: PathBuf,
+}
+
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    config: &bench::Config,
+    baselines: &BaselineArgs,
+    inputs_dir: &Path,
+) -> Result<()> {
+    let solutions = registry::solutions()
+        .filter(|solution| year.is_none_or(|year| solution.year() == year))
+        .filter(|solution| day.is_none_or(|day| solution.day() == day))
+        .filter(|solution| part.is_none_or(|part| solution.part() == part))
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        bail!("no solutions match the given year, day and part");
+    }
+
+    let baseline = match &baselines.compare {
+        Some(name) => Some(Baseline::load(&baselines.dir, name)?),
+        None => None,
     };
 
-    println!("{}", result);
+    let benchmarks = solutions
+        .into_iter()
+        .map(|solution| bench::run(config, solution, inputs_dir))
+        .collect::<Vec<_>>();
+
+    println!("{}", report::benchmarks(&benchmarks));
+
+    if let Some(baseline) = &baseline {
+        let changes = benchmarks
+            .iter()
+            .map(|benchmark| baseline::compare(benchmark, baseline, baselines.threshold))
+            .collect::<Vec<_>>();
+
+        println!();
+        println!("{}", report::comparison(&benchmarks, &changes, baseline));
+    }
+
+    if let Some(name) = &baselines.save {
+        let path = Baseline::from_benchmarks(&benchmarks).save(&baselines.dir, name)?;
+        println!();
+        println!("saved baseline {} to {}", name, path.display());
+    }
 
     Ok(())
 }
 
-mod day01;
+fn verify(year: Option<u16>, inputs_dir: &Path, answers: &Path) -> Result<()> {
+    if let Some(year) = year {
+        calendar(Some(year))?;
+    }
+    let answers = Answers::load(answers)?;
+    let outcomes = runner::run_all(inputs_dir, year);
+    let verdicts = outcomes
+        .iter()
+        .map(|outcome| answers::verify(outcome, &answers))
+        .collect::<Vec<_>>();
 
-mod day02;
+    println!("{}", report::verification(&outcomes, &verdicts));
 
-mod day03;
+    let failed = verdicts
+        .iter()
+        .filter(|verdict| matches!(verdict, Verdict::Incorrect { .. } | Verdict::Failed))
+        .count();
+    if failed > 0 {
+        bail!(
+            "{} of {} solutions failed verification",
+            failed,
+            outcomes.len()
+        );
+    }
 
-mod day04;
+    Ok(())
+}
 
-mod day05;
+fn client(config_path: &Path, server: ServerArgs) -> Result<Client> {
+    let config = Config::load(config_path)?;
+    let session = server.session.or(config.session).context(format!(
+        "no session token; set AOC_SESSION or add session to {}",
+        config_path.display()
+    ))?;
+    let base_url = server
+        .base_url
+        .or(config.base_url)
+        .unwrap_or_else(|| fetch::DEFAULT_BASE_URL.to_string());
 
-mod day06;
+    Ok(Client::new(
+        &base_url,
+        &session,
+        Path::new(config::DEFAULT_STATE_DIR),
+    ))
+}
 
-mod day07;
+fn fetch(year: Option<u16>, day: u8, client: &Client, inputs_dir: &Path) -> Result<()> {
+    let year = year.unwrap_or(registry::latest().year);
+    registry::check_day(year, day)?;
 
-mod day08;
+    match fetch::fetch(client, inputs_dir, year, day)? {
+        Fetched::Cached(path) => println!("{} is already cached", path.display()),
+        Fetched::Downloaded(path) => println!("wrote {}", path.display()),
+    }
 
-mod day09;
+    Ok(())
+}
 
-mod day10;
+fn submit(
+    year: Option<u16>,
+    day: u8,
+    part: u8,
+    client: &Client,
+    ledger_path: &Path,
+    inputs_dir: &Path,
+) -> Result<()> {
+    let solution = solution(year, day, part)?;
+    let year = solution.year();
+    let input = input::read_input(inputs_dir, year, day)?;
+    let answer = runner::run(solution, &input).answer?;
 
-mod day11;
+    let mut ledger = Ledger::load(ledger_path)?;
+    let response = submit::submit(client, &mut ledger, year, day, part, &answer)?;
+    ledger.save(ledger_path)?;
+
+    match response {
+        Response::Judged(Judgement::Correct) => println!("{} is correct", answer),
+        Response::Judged(judgement) => bail!("{} is {}", answer, judgement),
+        Response::RateLimited(Some(wait)) => {
+            bail!("answered too recently; try again in {}s", wait.as_secs())
+        }
+        Response::RateLimited(None) => bail!("answered too recently; try again later"),
+        Response::WrongLevel => bail!("part {} is locked or already solved", part),
+        Response::Unrecognized(text) => bail!("unrecognized response: {}", text),
+    }
 
-mod day12;
+    Ok(())
+}
+
+fn watch(
+    year: Option<u16>,
+    day: u8,
+    part: u8,
+    interval: Duration,
+    src_dir: &Path,
+    inputs_dir: &Path,
+) -> Result<()> {
+    let year = solution(year, day, part)?.year();
+    let mut watcher = Watcher::new(
+        &watch::day_dir(src_dir, year, day),
+        &input::input_path(inputs_dir, year, day),
+    );
+    let mut previous = None;
+
+    loop {
+        match watch::run_once(year, day, part, inputs_dir) {
+            Ok(record) => {
+                println!("{}", watch::describe(previous.as_ref(), &record));
+                previous = Some(record);
+            }
+            Err(error) => println!("error: {:#}", error),
+        }
+        println!("watching {} files", watcher.paths().count());
+
+        let changed = loop {
+            thread::sleep(interval);
+            let changed = watcher.poll();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        for path in changed {
+            println!("changed: {}", path.display());
+        }
+    }
+}
+
+fn new(
+    year: Option<u16>,
+    day: u8,
+    title: Option<String>,
+    src_dir: &Path,
+    examples_dir: &Path,
+    fuzz_dir: &Path,
+) -> Result<()> {
+    let year = year.unwrap_or(registry::latest().year);
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+
+    for path in scaffold::create(src_dir, examples_dir, fuzz_dir, year, day, &title)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run {
+            day,
+            part,
+            example: true,
+            format,
+            ..
+        } => run_examples(args.year, day, part, &args.examples_dir, format),
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            ..
+        } => run(
+            args.year,
+            day,
+            part,
+            input.as_deref(),
+            &args.inputs_dir,
+            format,
+        ),
+        Command::All { format } => all(args.year, &args.inputs_dir, format),
+        Command::Bench {
+            day,
+            part,
+            warmup,
+            measurement,
+            samples,
+            save,
+            compare,
+            threshold,
+            baselines_dir,
+        } => {
+            let config = bench::Config {
+                warmup: Duration::from_millis(warmup),
+                measurement: Duration::from_millis(measurement),
+                samples,
+            };
+            let baselines = BaselineArgs {
+                save,
+                compare,
+                threshold,
+                dir: baselines_dir,
+            };
+            bench(args.year, day, part, &config, &baselines, &args.inputs_dir)
+        }
+        Command::Verify { answers } => verify(args.year, &args.inputs_dir, &answers),
+        Command::Fetch { day, server } => {
+            let client = client(&args.config, server)?;
+            fetch(args.year, day, &client, &args.inputs_dir)
+        }
+        Command::Submit {
+            day,
+            part,
+            server,
+            ledger,
+        } => {
+            let client = client(&args.config, server)?;
+            submit(args.year, day, part, &client, &ledger, &args.inputs_dir)
+        }
+        Command::Watch {
+            day,
+            part,
+            interval,
+            src_dir,
+        } => watch(
+            args.year,
+            day,
+            part,
+            Duration::from_millis(interval),
+            &src_dir,
+            &args.inputs_dir,
+        ),
+        Command::New {
+            day,
+            title,
+            src_dir,
+            fuzz_dir,
+        } => new(
+            args.year,
+            day,
+            title,
+            &src_dir,
+            &args.examples_dir,
+            &fuzz_dir,
+        ),
+    }
+}
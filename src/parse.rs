@@ -0,0 +1,174 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+    span: Range<usize>,
+}
+
+fn addresses(s: &str) -> Range<usize> {
+    let start = s.as_ptr() as usize;
+    start..start + s.len()
+}
+
+impl ParseError {
+    // `snippet` must be a slice of the text being parsed so that `locate` can
+    // find it again; an empty slice marks a position, e.g. the end of input.
+    pub fn new(snippet: &str, message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+            location: None,
+            span: addresses(snippet),
+        }
+    }
+
+    pub fn locate(mut self, input: &str) -> Self {
+        let bounds = addresses(input);
+        if self.location.is_some() || self.span.start < bounds.start || self.span.end > bounds.end {
+            return self;
+        }
+
+        let start = self.span.start - bounds.start;
+        let end = self.span.end - bounds.start;
+        let line_start = input[..start].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[start..]
+            .find('\n')
+            .map_or(input.len(), |index| start + index);
+
+        self.location = Some(Location {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            width: input[start..end.min(line_end)].chars().count().max(1),
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(location) = &self.location else {
+            return write!(f, "{}", self.message);
+        };
+
+        let gutter = location.line.to_string().len();
+        write!(
+            f,
+            "{} at line {}, column {}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            self.message,
+            location.line,
+            location.column,
+            "",
+            location.line,
+            location.text,
+            "",
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width),
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn locate(error: anyhow::Error, input: &str) -> anyhow::Error {
+    match error.downcast::<ParseError>() {
+        Ok(error) => error.locate(input).into(),
+        Err(error) => error,
+    }
+}
+
+pub fn end(input: &str) -> &str {
+    &input[input.len()..]
+}
+
+pub fn number<T>(s: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.parse::<T>()
+        .map_err(|error| ParseError::new(s, format!("invalid number {:?}: {}", s, error)).into())
+}
+
+pub fn split_once(s: &str, delimiter: char) -> Result<(&str, &str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("expected {:?}", delimiter)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+
+    fn parse_pairs(input: &str) -> Result<Vec<(u8, u8)>> {
+        input
+            .lines()
+            .map(|line| {
+                let (a, b) = split_once(line, ',')?;
+                Ok((number(a)?, number(b)?))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "1,2\n3,x4\n";
+        let error = parse_pairs(input).unwrap_err();
+        let error = locate(error, input);
+
+        let location = error.downcast_ref::<ParseError>().unwrap().location.clone();
+        assert_eq!(
+            location,
+            Some(Location {
+                line: 2,
+                column: 3,
+                width: 2,
+                text: "3,x4".to_string(),
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid number \"x4\": invalid digit found in string at line 2, column 3
+  |
+2 | 3,x4
+  |   ^^"
+        );
+    }
+
+    #[test]
+    fn test_locate_end_of_input() -> Result<()> {
+        let input = "1,2\n";
+        let error = locate(ParseError::new(end(input), "expected more").into(), input);
+        let location = error.downcast_ref::<ParseError>().unwrap().location.clone();
+        assert_eq!(
+            location.map(|l| (l.line, l.column, l.width)),
+            Some((2, 1, 1))
+        );
+
+        let error = locate(parse_pairs("1;2").unwrap_err(), input);
+        assert_eq!(error.to_string(), "expected ','");
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_errors_are_unchanged() {
+        let error = (|| -> Result<()> { bail!("no tachyon start") })().unwrap_err();
+        assert_eq!(locate(error, "").to_string(), "no tachyon start");
+    }
+}
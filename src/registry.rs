@@ -0,0 +1,100 @@
+use anyhow::{Result, bail};
+
+use crate::solution::Solution;
+
+pub const FIRST_YEAR: u16 = 2015;
+
+pub struct Calendar {
+    pub year: u16,
+    pub days: u8,
+    pub solutions: &'static [&'static [&'static dyn Solution]],
+}
+
+impl Calendar {
+    pub fn solutions(&self) -> impl Iterator<Item = &'static dyn Solution> + use<> {
+        self.solutions.iter().flat_map(|day| day.iter().copied())
+    }
+}
+
+static CALENDARS: &[&Calendar] = &[&crate::y2025::CALENDAR];
+
+pub fn calendars() -> impl Iterator<Item = &'static Calendar> {
+    CALENDARS.iter().copied()
+}
+
+pub fn calendar(year: u16) -> Option<&'static Calendar> {
+    calendars().find(|calendar| calendar.year == year)
+}
+
+pub fn latest() -> &'static Calendar {
+    calendars()
+        .max_by_key(|calendar| calendar.year)
+        .expect("no calendars registered")
+}
+
+pub fn days_in(year: u16) -> u8 {
+    match calendar(year) {
+        Some(calendar) => calendar.days,
+        None if year >= 2025 => 12,
+        None => 25,
+    }
+}
+
+pub fn check_day(year: u16, day: u8) -> Result<u8> {
+    if year < FIRST_YEAR {
+        bail!("Advent of Code started in {}", FIRST_YEAR);
+    }
+    let days = days_in(year);
+    if !(1..=days).contains(&day) {
+        bail!("day must be between 1 and {} in {}", days, year);
+    }
+    Ok(days)
+}
+
+pub fn solutions() -> impl Iterator<Item = &'static dyn Solution> {
+    calendars().flat_map(Calendar::solutions)
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static dyn Solution> {
+    calendar(year)?
+        .solutions()
+        .find(|solution| solution.day() == day && solution.part() == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let solution = find(2025, 7, 2).unwrap();
+        assert_eq!(solution.year(), 2025);
+        assert_eq!(solution.day(), 7);
+        assert_eq!(solution.part(), 2);
+        assert_eq!(solution.title(), "Laboratories");
+    }
+
+    #[test]
+    fn test_find_missing() {
+        assert!(find(2025, 13, 1).is_none());
+        assert!(find(2025, 1, 3).is_none());
+        assert!(find(2014, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_calendar() {
+        let calendar = calendar(2025).unwrap();
+        assert_eq!(calendar.days, 12);
+        assert_eq!(calendar.solutions().count(), 24);
+        assert!(latest().year >= 2025);
+    }
+
+    #[test]
+    fn test_check_day() {
+        assert_eq!(check_day(2025, 12).unwrap(), 12);
+        assert_eq!(check_day(2016, 25).unwrap(), 25);
+        assert!(check_day(2025, 13).is_err());
+        assert!(check_day(2016, 0).is_err());
+        assert!(check_day(2014, 1).is_err());
+    }
+}
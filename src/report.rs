@@ -0,0 +1,333 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::answers::Verdict;
+use crate::baseline::{Baseline, Change};
+use crate::bench::Benchmark;
+use crate::runner::{self, Outcome};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub input_hash: Option<String>,
+}
+
+impl From<&Outcome> for Record {
+    fn from(outcome: &Outcome) -> Self {
+        let (answer, error) = match &outcome.answer {
+            Ok(answer) if answer.is_solved() => (Some(answer.to_string()), None),
+            Ok(_) => (None, None),
+            Err(error) => (None, Some(format!("{:#}", error))),
+        };
+
+        Record {
+            year: outcome.solution.year(),
+            day: outcome.solution.day(),
+            part: outcome.solution.part(),
+            title: outcome.solution.title().to_string(),
+            answer,
+            error,
+            parse_ns: outcome.parse_elapsed.as_nanos() as u64,
+            solve_ns: outcome.solve_elapsed.as_nanos() as u64,
+            input_hash: outcome.input_hash.clone(),
+        }
+    }
+}
+
+pub fn records(outcomes: &[Outcome]) -> Vec<Record> {
+    outcomes.iter().map(Record::from).collect()
+}
+
+fn error_summary(error: &anyhow::Error) -> String {
+    let message = format!("{:#}", error);
+    format!("error: {}", message.lines().next().unwrap_or_default())
+}
+
+// Located parse errors span several lines, so tables show their first line
+// and list them in full underneath.
+fn error_details(outcomes: &[Outcome]) -> Vec<String> {
+    let mut details = Vec::new();
+    for outcome in outcomes {
+        let Err(error) = &outcome.answer else {
+            continue;
+        };
+        let message = format!("{:#}", error);
+        let detail = format!(
+            "\n{} day {}: {}",
+            outcome.solution.year(),
+            outcome.solution.day(),
+            message
+        );
+        if message.contains('\n') && !details.contains(&detail) {
+            details.push(detail);
+        }
+    }
+    details
+}
+
+pub fn table(outcomes: &[Outcome]) -> String {
+    let mut lines = vec![format!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {:>10}  {:>10}",
+        "Year", "Day", "Part", "Title", "Answer", "Parse", "Solve"
+    )];
+
+    for outcome in outcomes {
+        let answer = match &outcome.answer {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error_summary(error),
+        };
+        lines.push(format!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {:>10}  {:>10}",
+            outcome.solution.year(),
+            outcome.solution.day(),
+            outcome.solution.part(),
+            outcome.solution.title(),
+            answer,
+            runner::format_duration(outcome.parse_elapsed),
+            runner::format_duration(outcome.solve_elapsed)
+        ));
+    }
+
+    lines.push(format!(
+        "{:<71}  {:>10}",
+        "Total",
+        runner::format_duration(runner::total_elapsed(outcomes))
+    ));
+    lines.extend(error_details(outcomes));
+
+    lines.join("\n")
+}
+
+pub fn verification(outcomes: &[Outcome], verdicts: &[Verdict]) -> String {
+    let mut lines = vec![format!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {:<20}  {}",
+        "Year", "Day", "Part", "Title", "Expected", "Actual", "Status"
+    )];
+
+    for (outcome, verdict) in outcomes.iter().zip(verdicts) {
+        let actual = match &outcome.answer {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error_summary(error),
+        };
+        let (expected, status) = match verdict {
+            Verdict::Correct => (actual.clone(), "ok"),
+            Verdict::Incorrect { expected } => (expected.to_string(), "WRONG"),
+            Verdict::Unknown => ("?".to_string(), "unknown"),
+            Verdict::Failed => ("?".to_string(), "FAILED"),
+        };
+        lines.push(format!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {:<20}  {}",
+            outcome.solution.year(),
+            outcome.solution.day(),
+            outcome.solution.part(),
+            outcome.solution.title(),
+            expected,
+            actual,
+            status
+        ));
+    }
+    lines.extend(error_details(outcomes));
+
+    lines.join("\n")
+}
+
+pub fn benchmarks(benchmarks: &[Benchmark]) -> String {
+    let mut lines = vec![format!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "Year", "Day", "Part", "Title", "Mean", "Median", "StdDev", "Min", "Max", "Outliers"
+    )];
+
+    for benchmark in benchmarks {
+        let solution = benchmark.solution;
+        let columns = match &benchmark.stats {
+            Ok(stats) => format!(
+                "{:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+                runner::format_duration(stats.mean),
+                runner::format_duration(stats.median),
+                runner::format_duration(stats.stddev),
+                runner::format_duration(stats.min),
+                runner::format_duration(stats.max),
+                format!("{}/{}", stats.outliers, stats.samples)
+            ),
+            Err(error) => error_summary(error),
+        };
+        lines.push(format!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {}",
+            solution.year(),
+            solution.day(),
+            solution.part(),
+            solution.title(),
+            columns
+        ));
+    }
+
+    lines.join("\n")
+}
+
+pub fn comparison(benchmarks: &[Benchmark], changes: &[Change], baseline: &Baseline) -> String {
+    let mut lines = vec![format!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {:>10}  {:>8}  {}",
+        "Year", "Day", "Part", "Title", "Baseline", "Median", "Change", "Status"
+    )];
+
+    for (benchmark, change) in benchmarks.iter().zip(changes) {
+        let solution = benchmark.solution;
+        let before = baseline
+            .median(solution.year(), solution.day(), solution.part())
+            .map(runner::format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        let after = benchmark
+            .stats
+            .as_ref()
+            .map(|stats| runner::format_duration(stats.median))
+            .unwrap_or_else(|_| "-".to_string());
+        let (percent, status) = match change {
+            Change::Faster(percent) => (format!("{:+.1}%", percent), "faster"),
+            Change::Slower(percent) => (format!("{:+.1}%", percent), "SLOWER"),
+            Change::Unchanged(percent) => (format!("{:+.1}%", percent), "unchanged"),
+            Change::Missing => ("-".to_string(), "no baseline"),
+        };
+        lines.push(format!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {:>10}  {:>8}  {}",
+            solution.year(),
+            solution.day(),
+            solution.part(),
+            solution.title(),
+            before,
+            after,
+            percent,
+            status
+        ));
+    }
+
+    lines.join("\n")
+}
+
+pub fn json(outcomes: &[Outcome]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&records(outcomes))?)
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn csv(outcomes: &[Outcome]) -> String {
+    let mut lines =
+        vec!["year,day,part,title,answer,error,parse_ns,solve_ns,input_hash".to_string()];
+
+    for record in records(outcomes) {
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record.title,
+            record.answer.unwrap_or_default(),
+            record.error.unwrap_or_default(),
+            record.parse_ns.to_string(),
+            record.solve_ns.to_string(),
+            record.input_hash.unwrap_or_default(),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::answer::Answer;
+    use crate::registry;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                solution: registry::find(2025, 1, 1).unwrap(),
+                input_hash: Some("abc123".to_string()),
+                answer: Ok(Answer::Unsigned(1135)),
+                parse_elapsed: Duration::from_micros(100),
+                solve_elapsed: Duration::from_micros(250),
+            },
+            Outcome {
+                solution: registry::find(2025, 1, 2).unwrap(),
+                input_hash: None,
+                answer: Err(anyhow!("invalid direction: \"X\", line 3")),
+                parse_elapsed: Duration::ZERO,
+                solve_elapsed: Duration::ZERO,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let value: serde_json::Value = serde_json::from_str(&json(&outcomes())?)?;
+        assert_eq!(value[0]["year"], 2025);
+        assert_eq!(value[0]["day"], 1);
+        assert_eq!(value[0]["answer"], "1135");
+        assert_eq!(value[0]["parse_ns"], 100_000);
+        assert_eq!(value[0]["solve_ns"], 250_000);
+        assert_eq!(value[0]["input_hash"], "abc123");
+        assert!(value[1]["answer"].is_null());
+        assert!(value[1]["error"].is_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_lists_located_errors() {
+        let mut outcomes = outcomes();
+        outcomes[1].answer = registry::find(2025, 1, 2).unwrap().run("L68\nX5\n");
+
+        let table = table(&outcomes);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[2].contains("error: invalid direction \"X\" at line 2, column 1"));
+        assert!(lines[3].starts_with("Total"));
+        assert_eq!(
+            lines[5..],
+            [
+                "2025 day 1: invalid direction \"X\" at line 2, column 1",
+                "  |",
+                "2 | X5",
+                "  | ^",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = csv(&outcomes());
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "year,day,part,title,answer,error,parse_ns,solve_ns,input_hash"
+        );
+        assert_eq!(
+            lines[1],
+            "2025,1,1,Secret Entrance,1135,,100000,250000,abc123"
+        );
+        assert_eq!(
+            lines[2],
+            "2025,1,2,Secret Entrance,,\"invalid direction: \"\"X\"\", line 3\",0,0,"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+
+use crate::answer::Answer;
+use crate::input;
+use crate::registry;
+use crate::solution::Solution;
+
+pub struct Outcome {
+    pub solution: &'static dyn Solution,
+    pub input_hash: Option<String>,
+    pub answer: Result<Answer>,
+    pub parse_elapsed: Duration,
+    pub solve_elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_elapsed + self.solve_elapsed
+    }
+}
+
+fn same_day(a: &&dyn Solution, b: &&dyn Solution) -> bool {
+    (a.year(), a.day()) == (b.year(), b.day())
+}
+
+pub fn run_parts(solutions: &[&'static dyn Solution], input: &str) -> Vec<Outcome> {
+    let Some(first) = solutions.first() else {
+        return Vec::new();
+    };
+    debug_assert!(solutions.iter().all(|s| same_day(s, first)));
+
+    let input_hash = input::hash(input);
+
+    let start = Instant::now();
+    let parsed = first.parse(input);
+    let parse_elapsed = start.elapsed();
+
+    solutions
+        .iter()
+        .map(|&solution| {
+            let (answer, solve_elapsed) = match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    let answer = solution.solve(parsed);
+                    (answer, start.elapsed())
+                }
+                Err(error) => (Err(anyhow!("{:#}", error)), Duration::ZERO),
+            };
+
+            Outcome {
+                solution,
+                input_hash: Some(input_hash.clone()),
+                answer,
+                parse_elapsed,
+                solve_elapsed,
+            }
+        })
+        .collect()
+}
+
+pub fn run(solution: &'static dyn Solution, input: &str) -> Outcome {
+    let mut outcomes = run_parts(&[solution], input);
+    outcomes.remove(0)
+}
+
+pub fn run_all(inputs_dir: &Path, year: Option<u16>) -> Vec<Outcome> {
+    let solutions = registry::solutions()
+        .filter(|solution| year.is_none_or(|year| solution.year() == year))
+        .collect::<Vec<_>>();
+    let mut outcomes = Vec::new();
+
+    for day_solutions in solutions.chunk_by(same_day) {
+        let (year, day) = (day_solutions[0].year(), day_solutions[0].day());
+
+        match input::read_input(inputs_dir, year, day) {
+            Ok(input) => outcomes.extend(run_parts(day_solutions, &input)),
+            Err(error) => outcomes.extend(day_solutions.iter().map(|&solution| Outcome {
+                solution,
+                input_hash: None,
+                answer: Err(anyhow!("{:#}", error)),
+                parse_elapsed: Duration::ZERO,
+                solve_elapsed: Duration::ZERO,
+            })),
+        }
+    }
+
+    outcomes
+}
+
+pub fn total_elapsed(outcomes: &[Outcome]) -> Duration {
+    let mut parsed_days = HashSet::new();
+
+    outcomes
+        .iter()
+        .map(|outcome| {
+            if parsed_days.insert((outcome.solution.year(), outcome.solution.day())) {
+                outcome.elapsed()
+            } else {
+                outcome.solve_elapsed
+            }
+        })
+        .sum()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.50µs");
+        assert_eq!(format_duration(Duration::from_micros(2_345)), "2.35ms");
+        assert_eq!(format_duration(Duration::from_millis(3_210)), "3.21s");
+    }
+
+    #[test]
+    fn test_run_parts_shares_parse() {
+        let solutions = [
+            registry::find(2025, 1, 1).unwrap(),
+            registry::find(2025, 1, 2).unwrap(),
+        ];
+        let outcomes = run_parts(&solutions, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82");
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].parse_elapsed, outcomes[1].parse_elapsed);
+        assert_eq!(*outcomes[0].answer.as_ref().unwrap(), Answer::Unsigned(3));
+        assert_eq!(*outcomes[1].answer.as_ref().unwrap(), Answer::Unsigned(6));
+        assert_eq!(
+            total_elapsed(&outcomes),
+            outcomes[0].parse_elapsed + outcomes[0].solve_elapsed + outcomes[1].solve_elapsed
+        );
+    }
+
+    #[test]
+    fn test_run_parts_parse_error() {
+        let solutions = [
+            registry::find(2025, 1, 1).unwrap(),
+            registry::find(2025, 1, 2).unwrap(),
+        ];
+        let outcomes = run_parts(&solutions, "X10");
+
+        assert!(outcomes.iter().all(|outcome| outcome.answer.is_err()));
+        assert!(
+            outcomes
+                .iter()
+                .all(|outcome| outcome.solve_elapsed.is_zero())
+        );
+    }
+
+    #[test]
+    fn test_run_all_missing_inputs() {
+        let outcomes = run_all(Path::new("does-not-exist"), None);
+        assert_eq!(outcomes.len(), registry::solutions().count());
+        assert_eq!(run_all(Path::new("does-not-exist"), Some(2014)).len(), 0);
+        assert!(outcomes.iter().all(|outcome| outcome.answer.is_err()));
+        assert_eq!(total_elapsed(&outcomes), Duration::ZERO);
+    }
+}
@@ -0,0 +1,468 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use crate::{examples, registry};
+
+pub const DEFAULT_SRC_DIR: &str = "src";
+
+pub const DEFAULT_FUZZ_DIR: &str = "fuzz";
+
+const MOD_TEMPLATE: &str = r#"pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Part, Solution};
+
+pub const TITLE: &str = __TITLE__;
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: __DAY__,
+        part: 1,
+        title: TITLE,
+        parse: parse_input,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: __DAY__,
+        part: 2,
+        title: TITLE,
+        parse: parse_input,
+        solve: part2::solve,
+    },
+];
+
+pub type Input = Vec<String>;
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"pub mod __MODULE__;
+
+use crate::registry::Calendar;
+
+pub const YEAR: u16 = __YEAR__;
+
+pub static CALENDAR: Calendar = Calendar {
+    year: YEAR,
+    days: __DAYS__,
+    solutions: &[&__MODULE__::SOLUTIONS],
+};
+"#;
+
+const PART_TEMPLATE: &str = r#"use anyhow::Result;
+
+use super::{Input, parse_input};
+use crate::answer::Answer;
+
+pub fn solve(_input: &Input) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
+"#;
+
+const EXAMPLES_TEMPLATE: &str = r#"[[example]]
+# part1 = ""
+# part2 = ""
+input = '''
+'''
+"#;
+
+const FUZZ_TARGET_TEMPLATE: &str = r#"#![no_main]
+
+use aoc::runner;
+use aoc::__YEAR_MODULE__::__MODULE__::SOLUTIONS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    runner::run_parts(&SOLUTIONS, input);
+});
+"#;
+
+const FUZZ_BIN_TEMPLATE: &str = r#"
+[[bin]]
+name = "__NAME__"
+path = "fuzz_targets/__NAME__.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+const ARRAY_WIDTH: usize = 60;
+
+fn module_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn year_module_name(year: u16) -> String {
+    format!("y{}", year)
+}
+
+pub fn fuzz_target_name(year: u16, day: u8) -> String {
+    format!("{}_{}", year_module_name(year), module_name(day))
+}
+
+pub fn render_year(year: u16, days: u8, day: u8) -> String {
+    YEAR_TEMPLATE
+        .replace("__MODULE__", &module_name(day))
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAYS__", &days.to_string())
+}
+
+pub fn render_mod(day: u8, title: &str) -> String {
+    MOD_TEMPLATE
+        .replace("__DAY__", &day.to_string())
+        .replace("__TITLE__", &format!("{:?}", title))
+}
+
+pub fn render_part() -> String {
+    PART_TEMPLATE.to_string()
+}
+
+pub fn render_fuzz_target(year: u16, day: u8) -> String {
+    FUZZ_TARGET_TEMPLATE
+        .replace("__YEAR_MODULE__", &year_module_name(year))
+        .replace("__MODULE__", &module_name(day))
+}
+
+fn insert_sorted(
+    source: &str,
+    line: &str,
+    is_entry: impl Fn(&str) -> bool,
+    what: &str,
+) -> Result<String> {
+    let mut lines = source.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        bail!("{} already contains {:?}", what, line.trim());
+    }
+
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| is_entry(existing))
+        .map(|(index, existing)| (index, *existing))
+        .collect::<Vec<_>>();
+    let Some(&(last, _)) = entries.last() else {
+        bail!("no entries to extend in {}", what);
+    };
+
+    let index = entries
+        .iter()
+        .find(|(_, existing)| *existing > line)
+        .map_or(last + 1, |&(index, _)| index);
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn insert_array_entry(source: &str, opener: &str, entry: &str, what: &str) -> Result<String> {
+    let Some(start) = source.find(opener) else {
+        bail!("no {:?} in {}", opener, what);
+    };
+    let contents_start = start + opener.len();
+    let Some(length) = source[contents_start..].find(']') else {
+        bail!("unterminated {:?} in {}", opener, what);
+    };
+    let contents_end = contents_start + length;
+
+    let mut entries = source[contents_start..contents_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect::<Vec<_>>();
+    if entries.contains(&entry) {
+        bail!("{} already contains {:?}", what, entry);
+    }
+    entries.push(entry);
+    entries.sort();
+
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let indent = &source[line_start..start];
+    let indent = &indent[..indent.len() - indent.trim_start().len()];
+
+    let single_line = entries.join(", ");
+    let array = if single_line.len() + 2 <= ARRAY_WIDTH {
+        single_line
+    } else {
+        let lines = entries
+            .iter()
+            .map(|entry| format!("{}    {},\n", indent, entry))
+            .collect::<String>();
+        format!("\n{}{}", lines, indent)
+    };
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..contents_start],
+        array,
+        &source[contents_end..]
+    ))
+}
+
+pub fn register_day(year_mod: &str, day: u8) -> Result<String> {
+    let module = module_name(day);
+    let year_mod = insert_sorted(
+        year_mod,
+        &format!("pub mod {};", module),
+        |line| line.starts_with("pub mod day"),
+        "the year module",
+    )?;
+    insert_array_entry(
+        &year_mod,
+        "solutions: &[",
+        &format!("&{}::SOLUTIONS", module),
+        "the year module",
+    )
+}
+
+pub fn register_year_module(lib: &str, year: u16) -> Result<String> {
+    insert_sorted(
+        lib,
+        &format!("pub mod {};", year_module_name(year)),
+        |line| line.starts_with("pub mod "),
+        "lib.rs",
+    )
+}
+
+pub fn register_calendar(registry: &str, year: u16) -> Result<String> {
+    insert_array_entry(
+        registry,
+        "static CALENDARS: &[&Calendar] = &[",
+        &format!("&crate::{}::CALENDAR", year_module_name(year)),
+        "registry.rs",
+    )
+}
+
+pub fn register_fuzz_target(manifest: &str, year: u16, day: u8) -> Result<String> {
+    let name = fuzz_target_name(year, day);
+    if manifest.contains(&format!("name = {:?}", name)) {
+        bail!("the fuzz manifest already contains {:?}", name);
+    }
+    Ok(manifest.to_string() + &FUZZ_BIN_TEMPLATE.replace("__NAME__", &name))
+}
+
+pub fn create(
+    src_dir: &Path,
+    examples_dir: &Path,
+    fuzz_dir: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>> {
+    let days = registry::check_day(year, day)?;
+
+    let year_dir = src_dir.join(year_module_name(year));
+    let day_dir = year_dir.join(module_name(day));
+    if day_dir.exists() {
+        bail!("{} already exists", day_dir.display());
+    }
+
+    let examples_path = examples::examples_path(examples_dir, year, day);
+    if examples_path.exists() {
+        bail!("{} already exists", examples_path.display());
+    }
+
+    let fuzz_manifest_path = fuzz_dir.join("Cargo.toml");
+    let fuzz_target_path = fuzz_dir
+        .join("fuzz_targets")
+        .join(format!("{}.rs", fuzz_target_name(year, day)));
+    if fuzz_target_path.exists() {
+        bail!("{} already exists", fuzz_target_path.display());
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+    };
+
+    let mut files = vec![
+        (day_dir.join("mod.rs"), render_mod(day, title)),
+        (day_dir.join("part1.rs"), render_part()),
+        (day_dir.join("part2.rs"), render_part()),
+        (examples_path, EXAMPLES_TEMPLATE.to_string()),
+    ];
+
+    if fuzz_manifest_path.exists() {
+        let manifest = register_fuzz_target(&read(&fuzz_manifest_path)?, year, day)?;
+        files.push((fuzz_target_path, render_fuzz_target(year, day)));
+        files.push((fuzz_manifest_path, manifest));
+    }
+
+    let year_mod_path = year_dir.join("mod.rs");
+    if year_dir.exists() {
+        let year_mod = register_day(&read(&year_mod_path)?, day)?;
+        files.push((year_mod_path, year_mod));
+    } else {
+        let lib_path = src_dir.join("lib.rs");
+        let registry_path = src_dir.join("registry.rs");
+        let lib = register_year_module(&read(&lib_path)?, year)?;
+        let registry = register_calendar(&read(&registry_path)?, year)?;
+        files.push((year_mod_path, render_year(year, days, day)));
+        files.push((lib_path, lib));
+        files.push((registry_path, registry));
+    }
+
+    for (path, contents) in &files {
+        let dir = path.parent().unwrap_or(src_dir);
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const LIB: &str = "pub mod answer;\npub mod input;\npub mod y2025;\n";
+
+    const REGISTRY: &str = "static CALENDARS: &[&Calendar] = &[&crate::y2025::CALENDAR];\n";
+
+    #[test]
+    fn test_register_year_module() -> Result<()> {
+        assert_eq!(
+            register_year_module(LIB, 2016)?,
+            "pub mod answer;\npub mod input;\npub mod y2016;\npub mod y2025;\n"
+        );
+        assert!(register_year_module(LIB, 2025).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_calendar() -> Result<()> {
+        assert_eq!(
+            register_calendar(REGISTRY, 2024)?,
+            "static CALENDARS: &[&Calendar] = &[&crate::y2024::CALENDAR, &crate::y2025::CALENDAR];\n"
+        );
+
+        let registry = register_calendar(&register_calendar(REGISTRY, 2024)?, 2023)?;
+        assert_eq!(
+            registry,
+            "static CALENDARS: &[&Calendar] = &[
+    &crate::y2023::CALENDAR,
+    &crate::y2024::CALENDAR,
+    &crate::y2025::CALENDAR,
+];
+"
+        );
+        assert!(register_calendar(REGISTRY, 2025).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_day() -> Result<()> {
+        let year_mod = register_day(&render_year(2024, 25, 3), 1)?;
+        assert!(year_mod.starts_with("pub mod day01;\npub mod day03;\n"));
+        assert!(year_mod.contains("solutions: &[&day01::SOLUTIONS, &day03::SOLUTIONS],"));
+        assert!(register_day(&year_mod, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_mod() {
+        let source = render_mod(13, "Some \"Quoted\" Title");
+        assert!(source.contains("pub const TITLE: &str = \"Some \\\"Quoted\\\" Title\";"));
+        assert!(source.contains("day: 13,\n        part: 2,"));
+    }
+
+    #[test]
+    fn test_register_fuzz_target() -> Result<()> {
+        let manifest = register_fuzz_target("[package]\n", 2016, 2)?;
+        assert!(manifest.contains("[[bin]]\nname = \"y2016_day02\"\n"));
+        assert!(manifest.contains("path = \"fuzz_targets/y2016_day02.rs\"\n"));
+        assert!(register_fuzz_target(&manifest, 2016, 2).is_err());
+
+        let target = render_fuzz_target(2016, 2);
+        assert!(target.contains("use aoc::y2016::day02::SOLUTIONS;"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let fuzz_dir = dir.join("fuzz");
+        fs::create_dir_all(&fuzz_dir)?;
+        fs::write(dir.join("lib.rs"), LIB)?;
+        fs::write(dir.join("registry.rs"), REGISTRY)?;
+        fs::write(fuzz_dir.join("Cargo.toml"), "[package]\n")?;
+
+        let files = create(
+            &dir,
+            &dir.join("examples"),
+            &fuzz_dir,
+            2016,
+            2,
+            "Bathroom Security",
+        )?;
+        assert_eq!(files.len(), 9);
+        assert!(fuzz_dir.join("fuzz_targets/y2016_day02.rs").exists());
+        assert!(dir.join("examples/2016/day02.toml").exists());
+        assert!(dir.join("y2016/day02/part2.rs").exists());
+        assert!(fs::read_to_string(dir.join("lib.rs"))?.contains("pub mod y2016;"));
+        assert!(fs::read_to_string(dir.join("registry.rs"))?.contains("&crate::y2016::CALENDAR"));
+
+        let files = create(
+            &dir,
+            &dir.join("examples"),
+            &dir.join("no-fuzz"),
+            2016,
+            1,
+            "No Time for a Taxicab",
+        )?;
+        assert_eq!(files.len(), 5);
+        assert!(
+            fs::read_to_string(dir.join("y2016/mod.rs"))?
+                .contains("&[&day01::SOLUTIONS, &day02::SOLUTIONS]")
+        );
+
+        assert!(
+            create(
+                &dir,
+                &dir.join("examples"),
+                &fuzz_dir,
+                2016,
+                2,
+                "Bathroom Security"
+            )
+            .is_err()
+        );
+        assert!(
+            create(
+                &dir,
+                &dir.join("examples"),
+                &fuzz_dir,
+                2016,
+                26,
+                "Out of Range"
+            )
+            .is_err()
+        );
+        assert!(
+            create(
+                &dir,
+                &dir.join("examples"),
+                &fuzz_dir,
+                2025,
+                13,
+                "Out of Range"
+            )
+            .is_err()
+        );
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
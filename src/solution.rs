@@ -0,0 +1,90 @@
+use std::any::Any;
+
+use anyhow::{Context, Result};
+
+use crate::answer::Answer;
+use crate::parse;
+
+pub type Parsed = Box<dyn Any>;
+
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Parsed>;
+    fn solve(&self, parsed: &Parsed) -> Result<Answer>;
+
+    fn run(&self, input: &str) -> Result<Answer> {
+        self.solve(&self.parse(input)?)
+    }
+}
+
+pub struct Part<I> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    pub parse: fn(&str) -> Result<I>,
+    pub solve: fn(&I) -> Result<Answer>,
+}
+
+impl<I: Any> Solution for Part<I> {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part(&self) -> u8 {
+        self.part
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        let parsed = (self.parse)(input).map_err(|error| parse::locate(error, input))?;
+        Ok(Box::new(parsed))
+    }
+
+    fn solve(&self, parsed: &Parsed) -> Result<Answer> {
+        let input = parsed.downcast_ref::<I>().context(format!(
+            "parsed input does not belong to {} day {}",
+            self.year, self.day
+        ))?;
+        (self.solve)(input)
+    }
+
+    fn run(&self, input: &str) -> Result<Answer> {
+        let parsed = (self.parse)(input).map_err(|error| parse::locate(error, input))?;
+        (self.solve)(&parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_parse_is_shared_between_parts() -> Result<()> {
+        let part1 = registry::find(2025, 1, 1).unwrap();
+        let part2 = registry::find(2025, 1, 2).unwrap();
+
+        let parsed = part1.parse("L68\nL30\nR48")?;
+        assert_eq!(part1.solve(&parsed)?, part1.run("L68\nL30\nR48")?);
+        assert_eq!(part2.solve(&parsed)?, part2.run("L68\nL30\nR48")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_rejects_other_days() -> Result<()> {
+        let parsed = registry::find(2025, 1, 1).unwrap().parse("L68")?;
+        assert!(registry::find(2025, 2, 1).unwrap().solve(&parsed).is_err());
+        Ok(())
+    }
+}
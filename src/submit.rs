@@ -0,0 +1,360 @@
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::fetch::Client;
+
+pub const DEFAULT_LEDGER_FILE: &str = ".aoc/submissions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Judgement {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+impl fmt::Display for Judgement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Judgement::Correct => write!(f, "correct"),
+            Judgement::TooHigh => write!(f, "too high"),
+            Judgement::TooLow => write!(f, "too low"),
+            Judgement::Incorrect => write!(f, "incorrect"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Response {
+    Judged(Judgement),
+    RateLimited(Option<Duration>),
+    WrongLevel,
+    Unrecognized(String),
+}
+
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+pub fn parse_response(html: &str) -> Response {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Response::Judged(Judgement::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Response::Judged(Judgement::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Response::Judged(Judgement::TooLow)
+        } else {
+            Response::Judged(Judgement::Incorrect)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Response::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unrecognized(text)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub judgement: Judgement,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    pub guesses: Vec<Guess>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadyGuessed(Judgement),
+    NotBelow(String),
+    NotAbove(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyGuessed(judgement) => {
+                write!(f, "already submitted and was {}", judgement)
+            }
+            Refusal::NotBelow(answer) => write!(f, "{} was already too high", answer),
+            Refusal::NotAbove(answer) => write!(f, "{} was already too low", answer),
+        }
+    }
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("failed to read ledger: {}", path.display()));
+            }
+        };
+        serde_json::from_str(&text).with_context(|| format!("invalid ledger: {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write ledger: {}", path.display()))
+    }
+
+    pub fn guesses(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| (guess.year, guess.day, guess.part) == (year, day, part))
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Option<Refusal> {
+        let guesses = self.guesses(year, day, part).collect::<Vec<_>>();
+
+        if let Some(guess) = guesses
+            .iter()
+            .find(|guess| guess.judgement == Judgement::Correct)
+        {
+            return Some(Refusal::AlreadySolved(guess.answer.clone()));
+        }
+        if let Some(guess) = guesses.iter().find(|guess| {
+            guess
+                .answer
+                .parse::<Answer>()
+                .is_ok_and(|guessed| guessed == *answer)
+        }) {
+            return Some(Refusal::AlreadyGuessed(guess.judgement));
+        }
+
+        let value = answer.as_integer()?;
+        let bound = |judgement| {
+            guesses
+                .iter()
+                .filter(move |guess| guess.judgement == judgement)
+                .filter_map(|guess| {
+                    Some((guess.answer.parse::<Answer>().ok()?.as_integer()?, guess))
+                })
+        };
+        if let Some((_, guess)) = bound(Judgement::TooHigh)
+            .filter(|&(high, _)| value >= high)
+            .min_by_key(|&(high, _)| high)
+        {
+            return Some(Refusal::NotBelow(guess.answer.clone()));
+        }
+        if let Some((_, guess)) = bound(Judgement::TooLow)
+            .filter(|&(low, _)| value <= low)
+            .max_by_key(|&(low, _)| low)
+        {
+            return Some(Refusal::NotAbove(guess.answer.clone()));
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &Answer, judgement: Judgement) {
+        self.guesses.push(Guess {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            judgement,
+        });
+    }
+}
+
+pub fn submit(
+    client: &Client,
+    ledger: &mut Ledger,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<Response> {
+    if !answer.is_solved() {
+        bail!("{} day {} part {} is not solved yet", year, day, part);
+    }
+    if let Some(refusal) = ledger.check(year, day, part, answer) {
+        bail!("refusing to submit {}: {}", answer, refusal);
+    }
+
+    let level = part.to_string();
+    let answer_text = answer.to_string();
+    let html = client.post(
+        &client.answer_url(year, day),
+        &[("level", &level), ("answer", &answer_text)],
+    )?;
+
+    let response = parse_response(&html);
+    if let Response::Judged(judgement) = response {
+        ledger.record(year, day, part, answer, judgement);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::mock::serve;
+
+    const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using the full input data. \
+        <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article></main></body></html>";
+
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 4m 52s left to wait. \
+        <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>"
+            ),
+            Response::Judged(Judgement::Correct)
+        );
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Response::Judged(Judgement::TooHigh)
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>That's not the right answer.  Curiously, it's the right answer for someone else.</p></article>"
+            ),
+            Response::Judged(Judgement::Incorrect)
+        );
+        assert_eq!(
+            parse_response(RATE_LIMITED),
+            Response::RateLimited(Some(Duration::from_secs(292)))
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"
+            ),
+            Response::WrongLevel
+        );
+        assert!(
+            matches!(parse_response("<p>Hello</p>"), Response::Unrecognized(text) if text == "Hello")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut ledger = Ledger::default();
+        ledger.record(2025, 1, 1, &Answer::Unsigned(500), Judgement::TooHigh);
+        ledger.record(2025, 1, 1, &Answer::Unsigned(100), Judgement::TooLow);
+        ledger.record(2025, 1, 1, &Answer::Unsigned(300), Judgement::Incorrect);
+
+        assert_eq!(ledger.check(2025, 1, 1, &Answer::Unsigned(200)), None);
+        assert_eq!(
+            ledger.check(2025, 1, 1, &Answer::Unsigned(300)),
+            Some(Refusal::AlreadyGuessed(Judgement::Incorrect))
+        );
+        assert_eq!(
+            ledger.check(2025, 1, 1, &Answer::Unsigned(600)),
+            Some(Refusal::NotBelow("500".to_string()))
+        );
+        assert_eq!(
+            ledger.check(2025, 1, 1, &Answer::Signed(-5)),
+            Some(Refusal::NotAbove("100".to_string()))
+        );
+        assert_eq!(ledger.check(2025, 1, 2, &Answer::Unsigned(600)), None);
+
+        ledger.record(2025, 1, 1, &Answer::Unsigned(250), Judgement::Correct);
+        assert_eq!(
+            ledger.check(2025, 1, 1, &Answer::Unsigned(200)),
+            Some(Refusal::AlreadySolved("250".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_submit() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let (base_url, requests) = serve(vec![(200, TOO_HIGH), (200, RATE_LIMITED)]);
+        let mut client = Client::new(&base_url, "secret", &dir);
+        client.throttle = Duration::ZERO;
+        let mut ledger = Ledger::default();
+
+        let response = submit(&client, &mut ledger, 2025, 1, 2, &Answer::Unsigned(6558))?;
+        assert_eq!(response, Response::Judged(Judgement::TooHigh));
+        let request = requests.recv()?;
+        assert!(
+            request
+                .to_lowercase()
+                .starts_with("post /2025/day/1/answer ")
+        );
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=6558"));
+
+        assert!(submit(&client, &mut ledger, 2025, 1, 2, &Answer::Unsigned(7000)).is_err());
+        assert!(requests.try_recv().is_err());
+
+        let response = submit(&client, &mut ledger, 2025, 1, 2, &Answer::Unsigned(10))?;
+        assert_eq!(
+            response,
+            Response::RateLimited(Some(Duration::from_secs(292)))
+        );
+        assert_eq!(ledger.guesses(2025, 1, 2).count(), 1);
+
+        let path = dir.join("submissions.json");
+        ledger.save(&path)?;
+        assert_eq!(Ledger::load(&path)?.guesses.len(), 1);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
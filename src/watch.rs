@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result, bail};
+
+use crate::report::Record;
+use crate::runner;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn day_dir(src_dir: &Path, year: u16, day: u8) -> PathBuf {
+    src_dir
+        .join(format!("y{}", year))
+        .join(format!("day{:02}", day))
+}
+
+fn collect_sources(dir: &Path, sources: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_sources(&path, sources);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            sources.push(path);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+pub struct Watcher {
+    sources: PathBuf,
+    input: PathBuf,
+    snapshot: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(sources: &Path, input: &Path) -> Self {
+        let mut watcher = Self {
+            sources: sources.to_path_buf(),
+            input: input.to_path_buf(),
+            snapshot: BTreeMap::new(),
+        };
+        watcher.snapshot = watcher.take_snapshot();
+        watcher
+    }
+
+    fn take_snapshot(&self) -> BTreeMap<PathBuf, Option<SystemTime>> {
+        let mut paths = vec![self.input.clone()];
+        collect_sources(&self.sources, &mut paths);
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect()
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.snapshot.keys().map(PathBuf::as_path)
+    }
+
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let snapshot = self.take_snapshot();
+
+        let mut changed = snapshot
+            .iter()
+            .filter(|(path, modified)| self.snapshot.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(
+            self.snapshot
+                .keys()
+                .filter(|path| !snapshot.contains_key(*path))
+                .cloned(),
+        );
+
+        self.snapshot = snapshot;
+        changed
+    }
+}
+
+pub fn run_once(year: u16, day: u8, part: u8, inputs_dir: &Path) -> Result<Record> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let output = Command::new(cargo)
+        .args(["run", "--release", "--quiet", "--"])
+        .arg("--year")
+        .arg(year.to_string())
+        .arg("--inputs-dir")
+        .arg(inputs_dir)
+        .args([
+            "run",
+            &day.to_string(),
+            &part.to_string(),
+            "--format",
+            "json",
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("failed to start cargo")?;
+
+    let records = serde_json::from_slice::<Vec<Record>>(&output.stdout).ok();
+    match records.and_then(|records| records.into_iter().next()) {
+        Some(record) => Ok(record),
+        None => bail!("build or run failed ({})", output.status),
+    }
+}
+
+fn answer(record: &Record) -> String {
+    match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(error)) => format!("error: {}", error),
+        (None, None) => "not implemented".to_string(),
+    }
+}
+
+pub fn describe(previous: Option<&Record>, current: &Record) -> String {
+    let answer = answer(current);
+    let change = match previous.map(self::answer) {
+        None => String::new(),
+        Some(previous) if previous == answer => " (unchanged)".to_string(),
+        Some(previous) => format!(" (was {})", previous),
+    };
+
+    format!(
+        "answer: {}{}  parse {}  solve {}",
+        answer,
+        change,
+        runner::format_duration(Duration::from_nanos(current.parse_ns)),
+        runner::format_duration(Duration::from_nanos(current.solve_ns))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    fn record(answer: Option<&str>) -> Record {
+        Record {
+            year: 2025,
+            day: 1,
+            part: 1,
+            title: "Secret Entrance".to_string(),
+            answer: answer.map(str::to_string),
+            error: None,
+            parse_ns: 1_500,
+            solve_ns: 2_000_000,
+            input_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        let first = record(Some("1135"));
+        assert_eq!(
+            describe(None, &first),
+            "answer: 1135  parse 1.50µs  solve 2.00ms"
+        );
+        assert!(describe(Some(&first), &first).starts_with("answer: 1135 (unchanged)"));
+        assert!(
+            describe(Some(&first), &record(Some("1136"))).starts_with("answer: 1136 (was 1135)")
+        );
+        assert!(
+            describe(Some(&first), &record(None)).starts_with("answer: not implemented (was 1135)")
+        );
+    }
+
+    #[test]
+    fn test_poll() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let sources = day_dir(&dir, 2025, 1);
+        let input = dir.join("day01.txt");
+        fs::create_dir_all(&sources)?;
+        fs::write(sources.join("mod.rs"), "")?;
+        fs::write(sources.join("notes.txt"), "")?;
+
+        let mut watcher = Watcher::new(&sources, &input);
+        assert_eq!(watcher.paths().count(), 2);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&input, "L68")?;
+        assert_eq!(watcher.poll(), vec![input.clone()]);
+
+        let part1 = sources.join("part1.rs");
+        fs::write(&part1, "")?;
+        assert_eq!(watcher.poll(), vec![part1.clone()]);
+
+        File::options()
+            .write(true)
+            .open(&part1)?
+            .set_modified(SystemTime::now() + Duration::from_secs(60))?;
+        assert_eq!(watcher.poll(), vec![part1.clone()]);
+
+        fs::remove_file(&part1)?;
+        assert_eq!(watcher.poll(), vec![part1]);
+        assert!(watcher.poll().is_empty());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -3,13 +3,34 @@ use std::fmt;
 pub mod part1;
 pub mod part2;
 
-#[cfg(test)]
-mod bench;
-
 use std::collections::VecDeque;
 
 use anyhow::{Result, bail};
 
+use crate::parse::{self, ParseError};
+use crate::solution::{Part, Solution};
+
+pub const TITLE: &str = "Secret Entrance";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 1,
+        part: 1,
+        title: TITLE,
+        parse: parse_lines,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 1,
+        part: 2,
+        title: TITLE,
+        parse: parse_lines,
+        solve: part2::solve,
+    },
+];
+
 #[derive(Debug, PartialEq)]
 pub enum Direction {
     Left,
@@ -31,20 +52,25 @@ impl fmt::Display for Rotation {
 }
 
 pub fn parse_line(line: &str) -> Result<Rotation> {
-    let (direction, steps) = line.split_at(1);
+    let (direction, steps) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
 
     let direction = match direction {
         "L" => Direction::Left,
         "R" => Direction::Right,
-        _ => bail!("invalid direction: {}", direction),
+        _ => bail!(ParseError::new(
+            direction,
+            format!("invalid direction {:?}", direction)
+        )),
     };
 
-    let steps = steps.parse::<usize>()?;
+    let steps = parse::number::<usize>(steps)?;
 
     Ok(Rotation { direction, steps })
 }
 
-pub fn parse_lines(lines: &str) -> Result<Vec<Rotation>> {
+pub type Input = Vec<Rotation>;
+
+pub fn parse_lines(lines: &str) -> Result<Input> {
     lines.lines().map(parse_line).collect::<Result<Vec<_>>>()
 }
 
@@ -85,6 +111,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_error_location() {
+        let input = "L68\nR30\nX5\n";
+        let error = SOLUTIONS[0].parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid direction \"X\" at line 3, column 1\n  |\n3 | X5\n  | ^"
+        );
+
+        let input = "L68\nR3O\n";
+        let error = SOLUTIONS[0].parse(input).err().unwrap();
+        assert!(error.to_string().contains("line 2, column 2"));
+    }
+
     #[test]
     fn test_create_dial() {
         let dial = create_dial();
@@ -0,0 +1,28 @@
+use anyhow::Result;
+
+use super::{Direction, Input, create_dial, parse_lines};
+use crate::answer::Answer;
+
+pub fn solve(rotations: &Input) -> Result<Answer> {
+    let mut dial = create_dial();
+
+    let mut times_at_zero: u64 = 0;
+    for rotation in rotations {
+        let steps = rotation.steps % 100;
+
+        match rotation.direction {
+            Direction::Left => dial.rotate_left(steps),
+            Direction::Right => dial.rotate_right(steps),
+        }
+
+        if dial.front() == Some(&0) {
+            times_at_zero += 1;
+        }
+    }
+
+    Ok(times_at_zero.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_lines(input)?)
+}
@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+
+use super::{Direction, Input, create_dial, parse_lines};
+use crate::answer::Answer;
+
+pub fn solve(rotations: &Input) -> Result<Answer> {
+    let mut dial = create_dial();
+
+    let mut times_at_zero: usize = 0;
+    for rotation in rotations {
+        let current = *dial.front().context("dial is empty")?;
+        let mut clicks = rotation.steps / 100;
+        let steps = rotation.steps % 100;
+
+        match rotation.direction {
+            Direction::Left => {
+                if steps > current && current != 0 {
+                    clicks += 1;
+                }
+
+                dial.rotate_left(steps)
+            }
+            Direction::Right => {
+                if steps + current > 100 {
+                    clicks += 1;
+                }
+
+                dial.rotate_right(steps)
+            }
+        }
+
+        if dial.front() == Some(&0) {
+            clicks += 1;
+        }
+        times_at_zero = clicks
+            .checked_add(times_at_zero)
+            .context("too many clicks to count")?;
+    }
+
+    Ok(times_at_zero.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_lines(input)?)
+}
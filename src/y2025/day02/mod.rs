@@ -0,0 +1,95 @@
+use anyhow::Result;
+use std::ops::RangeInclusive;
+
+use crate::intervals::RangeSet;
+use crate::parse;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Gift Shop";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 2,
+        part: 1,
+        title: TITLE,
+        parse: parse_ranges,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 2,
+        part: 2,
+        title: TITLE,
+        parse: parse_ranges,
+        solve: part2::solve,
+    },
+];
+
+fn parse_range(line: &str) -> Result<RangeInclusive<usize>> {
+    let (min, max) = parse::split_once(line, '-')?;
+    let min = parse::number::<usize>(min)?;
+    let max = parse::number::<usize>(max)?;
+
+    Ok(min..=max)
+}
+
+// An ID listed in several ranges is counted once per range, so the ranges
+// are merged into layers: layer `k` holds the IDs listed more than `k` times.
+pub type Input = Vec<RangeSet<usize>>;
+
+pub fn parse_ranges(input: &str) -> Result<Input> {
+    let mut layers: Input = Vec::new();
+
+    for range in input.trim().split(',').map(parse_range) {
+        let range = RangeSet::from_iter([range?]);
+        for k in (0..layers.len()).rev() {
+            let overlap = layers[k].intersection(&range);
+            if overlap.is_empty() {
+                continue;
+            }
+            if k + 1 == layers.len() {
+                layers.push(overlap);
+            } else {
+                layers[k + 1].merge(&overlap);
+            }
+        }
+        match layers.first_mut() {
+            Some(first) => first.merge(&range),
+            None => layers.push(range),
+        }
+    }
+
+    Ok(layers)
+}
+
+pub fn digit_count(number: usize) -> usize {
+    if number == 0 {
+        return 1;
+    }
+    number.ilog10() as usize + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_overlapping_ranges_are_counted_separately() -> Result<()> {
+        let ranges = parse_ranges("11-22,11-22,20-33\n")?;
+        let layers = ranges.iter().map(|layer| layer.iter().collect::<Vec<_>>());
+        assert_eq!(
+            layers.collect::<Vec<_>>(),
+            vec![vec![11..=33], vec![11..=22], vec![20..=22]]
+        );
+        assert_eq!(
+            part1::solve(&ranges)?,
+            Answer::from(11 + 22 + 11 + 22 + 22 + 33)
+        );
+        Ok(())
+    }
+}
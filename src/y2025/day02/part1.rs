@@ -0,0 +1,31 @@
+use anyhow::{Context, Result};
+
+use super::{Input, digit_count, parse_ranges};
+use crate::answer::Answer;
+
+fn is_repeating(number: usize) -> bool {
+    let digits = digit_count(number);
+
+    if !digits.is_multiple_of(2) {
+        return false;
+    }
+
+    let divisor = 10_usize.pow((digits / 2) as u32);
+    number / divisor == number % divisor
+}
+
+pub fn solve(ranges: &Input) -> Result<Answer> {
+    let sum = ranges
+        .iter()
+        .flat_map(|layer| layer.iter())
+        .flatten()
+        .filter(|&n| is_repeating(n))
+        .try_fold(0_usize, usize::checked_add)
+        .context("sum overflows")?;
+
+    Ok(sum.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_ranges(input)?)
+}
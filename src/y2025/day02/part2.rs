@@ -1,6 +1,7 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 
-use super::{digit_count, parse_ranges};
+use super::{Input, digit_count, parse_ranges};
+use crate::answer::Answer;
 
 fn is_repeating_by_base(number: usize, base: usize) -> bool {
     let pow = 10_usize.pow(base as u32);
@@ -25,7 +26,7 @@ fn is_repeating_by_base(number: usize, base: usize) -> bool {
 fn is_repeating(number: usize) -> bool {
     let digits = digit_count(number);
 
-    for base in 1..=digits {
+    for base in 1..digits {
         if !digits.is_multiple_of(base) {
             continue;
         }
@@ -37,26 +38,18 @@ fn is_repeating(number: usize) -> bool {
     false
 }
 
-pub fn run(input: &str) -> Result<String> {
-    let ranges = parse_ranges(input)?;
-
-    let sum: usize = ranges
-        .into_iter()
+pub fn solve(ranges: &Input) -> Result<Answer> {
+    let sum = ranges
+        .iter()
+        .flat_map(|layer| layer.iter())
         .flatten()
         .filter(|&n| is_repeating(n))
-        .sum();
+        .try_fold(0_usize, usize::checked_add)
+        .context("sum overflows")?;
 
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example() -> Result<()> {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        assert_eq!(run(input)?, "4174379265");
-        Ok(())
-    }
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_ranges(input)?)
 }
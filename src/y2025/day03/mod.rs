@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use crate::parse::ParseError;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Lobby";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 3,
+        part: 1,
+        title: TITLE,
+        parse: parse_lines,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 3,
+        part: 2,
+        title: TITLE,
+        parse: parse_lines,
+        solve: part2::solve,
+    },
+];
+
+pub type Input = Vec<Vec<u8>>;
+
+pub fn parse_lines(input: &str) -> Result<Input> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(d) => Ok(d as u8),
+                    None => Err(ParseError::new(
+                        &line[i..i + c.len_utf8()],
+                        format!("invalid digit {:?}", c),
+                    )
+                    .into()),
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()
+}
+
+pub fn find_index(digits: &[u8], number: u8) -> Option<usize> {
+    digits.iter().position(|&d| d == number)
+}
@@ -0,0 +1,34 @@
+use anyhow::{Context, Result};
+
+use super::{Input, find_index, parse_lines};
+use crate::answer::Answer;
+
+fn get_max_jolt(digits: &[u8]) -> Option<u64> {
+    let first_digits = &digits[0..digits.len().checked_sub(1)?];
+    for first in (0..=9).rev() {
+        if let Some(index) = find_index(first_digits, first) {
+            let start = index + 1;
+            let second_digits = &digits[start..];
+            for second in (0..=9).rev() {
+                if find_index(second_digits, second).is_some() {
+                    return Some(first as u64 * 10 + second as u64);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn solve(banks: &Input) -> Result<Answer> {
+    let sum = banks
+        .iter()
+        .map(|digits| get_max_jolt(digits).context("bank has fewer than 2 batteries"))
+        .sum::<Result<u64>>()?;
+
+    Ok(sum.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_lines(input)?)
+}
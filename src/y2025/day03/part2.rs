@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+
+use super::{Input, find_index, parse_lines};
+use crate::answer::Answer;
+
+fn get_max_jolt(digits: &[u8]) -> Option<u64> {
+    let mut max_jolt = 0;
+    let mut next_digits = digits;
+    for depth in (0..12).rev() {
+        let current_digits = &next_digits[0..next_digits.len().checked_sub(depth)?];
+
+        for number in (0..=9).rev() {
+            if let Some(index) = find_index(current_digits, number) {
+                max_jolt = max_jolt * 10 + number as u64;
+                next_digits = &next_digits[index + 1..];
+                break;
+            }
+        }
+    }
+
+    Some(max_jolt)
+}
+
+pub fn solve(banks: &Input) -> Result<Answer> {
+    let sum = banks
+        .iter()
+        .map(|digits| get_max_jolt(digits).context("bank has fewer than 12 batteries"))
+        .sum::<Result<u64>>()?;
+
+    Ok(sum.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_lines(input)?)
+}
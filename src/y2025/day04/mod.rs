@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use crate::grid::{Grid, Point};
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Printing Department";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 4,
+        part: 1,
+        title: TITLE,
+        parse: parse_locations,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 4,
+        part: 2,
+        title: TITLE,
+        parse: parse_locations,
+        solve: part2::solve,
+    },
+];
+
+pub type Locations = Grid<bool>;
+
+pub fn parse_locations(input: &str) -> Result<Locations> {
+    Grid::parse(input, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+pub fn can_be_accessed(point: Point, locations: &Locations) -> bool {
+    if locations.get(point) != Some(&true) {
+        return false;
+    }
+
+    locations
+        .neighbours8(point)
+        .filter(|&neighbour| locations[neighbour])
+        .count()
+        < 4
+}
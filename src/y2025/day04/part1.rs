@@ -0,0 +1,17 @@
+use anyhow::Result;
+
+use super::{Locations, can_be_accessed, parse_locations};
+use crate::answer::Answer;
+
+pub fn solve(locations: &Locations) -> Result<Answer> {
+    let accessible_locations = locations
+        .points()
+        .filter(|&point| can_be_accessed(point, locations))
+        .count();
+
+    Ok(accessible_locations.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_locations(input)?)
+}
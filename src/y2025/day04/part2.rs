@@ -0,0 +1,36 @@
+use anyhow::Result;
+
+use super::{Locations, can_be_accessed, parse_locations};
+use crate::answer::Answer;
+use crate::grid::Point;
+
+fn get_possible_moves(locations: &Locations) -> Vec<Point> {
+    locations
+        .points()
+        .filter(|&point| can_be_accessed(point, locations))
+        .collect()
+}
+
+pub fn solve(locations: &Locations) -> Result<Answer> {
+    let mut locations = locations.clone();
+
+    let mut total_moves = 0;
+    loop {
+        let moves = get_possible_moves(&locations);
+        if moves.is_empty() {
+            break;
+        }
+
+        total_moves += moves.len();
+
+        for point in moves {
+            locations[point] = false;
+        }
+    }
+
+    Ok(total_moves.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_locations(input)?)
+}
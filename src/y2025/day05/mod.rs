@@ -0,0 +1,59 @@
+use anyhow::{Result, bail};
+
+use crate::intervals::RangeSet;
+use crate::parse::{self, ParseError};
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Cafeteria";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 5,
+        part: 1,
+        title: TITLE,
+        parse: parse_database,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 5,
+        part: 2,
+        title: TITLE,
+        parse: parse_database,
+        solve: part2::solve,
+    },
+];
+
+pub type Input = (RangeSet<u64>, Vec<u64>);
+
+pub fn parse_database(input: &str) -> Result<Input> {
+    let mut lines = input.lines();
+
+    let mut ranges = RangeSet::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let (min, max) = parse::split_once(line, '-')?;
+        let min = parse::number::<u64>(min)?;
+        let max = parse::number::<u64>(max)?;
+        if min > max {
+            bail!(ParseError::new(line, "range starts after it ends"));
+        }
+
+        ranges.insert(min..=max);
+    }
+
+    let mut ingredients = Vec::new();
+    for line in lines {
+        let number = parse::number::<u64>(line)?;
+        ingredients.push(number);
+    }
+
+    Ok((ranges, ingredients))
+}
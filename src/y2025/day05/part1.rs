@@ -0,0 +1,14 @@
+use anyhow::Result;
+
+use super::{Input, parse_database};
+use crate::answer::Answer;
+
+pub fn solve((ranges, ingredients): &Input) -> Result<Answer> {
+    let fresh_ingredients = ingredients.iter().filter(|&&i| ranges.contains(i)).count();
+
+    Ok(fresh_ingredients.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_database(input)?)
+}
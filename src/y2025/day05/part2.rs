@@ -0,0 +1,16 @@
+use anyhow::{Context, Result};
+
+use super::{Input, parse_database};
+use crate::answer::Answer;
+
+pub fn solve((ranges, _): &Input) -> Result<Answer> {
+    let total = ranges
+        .len()
+        .context("too many fresh ingredients to count")?;
+
+    Ok(total.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_database(input)?)
+}
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+
+use crate::parse::ParseError;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Trash Compactor";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 6,
+        part: 1,
+        title: TITLE,
+        parse: parse_worksheet,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 6,
+        part: 2,
+        title: TITLE,
+        parse: parse_worksheet,
+        solve: part2::solve,
+    },
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "*" => Ok(Operator::Multiply),
+            "+" => Ok(Operator::Add),
+            _ => Err(ParseError::new(s, format!("invalid operator {:?}", s))),
+        }
+    }
+}
+
+impl Operator {
+    pub fn apply(self, operands: &[u64]) -> Option<u64> {
+        match self {
+            Operator::Add => operands.iter().try_fold(0_u64, |a, &b| a.checked_add(b)),
+            Operator::Multiply => operands.iter().try_fold(1_u64, |a, &b| a.checked_mul(b)),
+        }
+    }
+}
+
+pub struct Worksheet {
+    pub rows: Vec<String>,
+    pub operators: Vec<Operator>,
+}
+
+pub fn parse_worksheet(input: &str) -> Result<Worksheet> {
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let last_line = lines.pop().context("no last line")?;
+
+    for line in &lines {
+        if let Some((i, c)) = line
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+        {
+            bail!(ParseError::new(
+                &line[i..i + c.len_utf8()],
+                format!("invalid operand character {:?}", c)
+            ));
+        }
+    }
+
+    let operators = last_line
+        .split_whitespace()
+        .map(|op| op.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rows = lines.into_iter().map(str::to_string).collect();
+    Ok(Worksheet { rows, operators })
+}
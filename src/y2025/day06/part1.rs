@@ -0,0 +1,43 @@
+use anyhow::{Context, Result};
+
+use super::{Worksheet, parse_worksheet};
+use crate::answer::Answer;
+
+fn parse_operands(lines: &[String]) -> Result<Vec<Vec<u64>>> {
+    let num_columns = lines
+        .first()
+        .map(|line| line.split_whitespace().count())
+        .unwrap_or(0);
+
+    let mut operands: Vec<Vec<u64>> = vec![Vec::new(); num_columns];
+
+    for line in lines {
+        for (i, operand) in line.split_whitespace().enumerate() {
+            let operand = operand.parse::<u64>()?;
+            operands
+                .get_mut(i)
+                .context("row has more operands than the first row")?
+                .push(operand);
+        }
+    }
+
+    Ok(operands)
+}
+
+pub fn solve(worksheet: &Worksheet) -> Result<Answer> {
+    let operands = parse_operands(&worksheet.rows)?;
+
+    let sum = operands
+        .iter()
+        .zip(worksheet.operators.iter().copied())
+        .try_fold(0_u64, |sum, (operands, operator)| {
+            sum.checked_add(operator.apply(operands)?)
+        })
+        .context("result overflows")?;
+
+    Ok(sum.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_worksheet(input)?)
+}
@@ -0,0 +1,51 @@
+use anyhow::{Context, Result};
+
+use super::{Worksheet, parse_worksheet};
+use crate::answer::Answer;
+
+fn parse_operands(lines: &[String], num_operators: usize) -> Result<Vec<Vec<u64>>> {
+    let max_width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut operands: Vec<Vec<u64>> = vec![Vec::new(); num_operators];
+    let mut operation_index = 0;
+
+    for col_idx in (0..max_width).rev() {
+        let column_chars: String = lines
+            .iter()
+            .filter_map(|line| line.chars().nth(col_idx))
+            .collect();
+
+        if column_chars.trim().is_empty() {
+            operation_index += 1;
+            continue;
+        }
+
+        for num_str in column_chars.split_whitespace() {
+            let num = num_str.parse::<u64>()?;
+            operands
+                .get_mut(operation_index)
+                .context("more problems than operators")?
+                .push(num);
+        }
+    }
+
+    Ok(operands)
+}
+
+pub fn solve(worksheet: &Worksheet) -> Result<Answer> {
+    let operators = worksheet.operators.iter().rev().copied();
+    let operands = parse_operands(&worksheet.rows, operators.len())?;
+
+    let sum = operands
+        .iter()
+        .zip(operators)
+        .try_fold(0_u64, |sum, (operands, operator)| {
+            sum.checked_add(operator.apply(operands)?)
+        })
+        .context("result overflows")?;
+
+    Ok(sum.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_worksheet(input)?)
+}
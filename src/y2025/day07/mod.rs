@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+
+use crate::grid::{Grid, Point};
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Laboratories";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 7,
+        part: 1,
+        title: TITLE,
+        parse: parse_input,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 7,
+        part: 2,
+        title: TITLE,
+        parse: parse_input,
+        solve: part2::solve,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Splitter,
+    Start,
+}
+
+pub type Input = (Point, Grid<Cell>);
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    let manifold = Grid::parse(input, |c| match c {
+        '.' => Some(Cell::Empty),
+        '^' => Some(Cell::Splitter),
+        'S' => Some(Cell::Start),
+        _ => None,
+    })?;
+
+    let tachyon_start = manifold
+        .iter()
+        .find(|&(_, &cell)| cell == Cell::Start)
+        .map(|(point, _)| point)
+        .context("no tachyon start")?;
+
+    Ok((tachyon_start, manifold))
+}
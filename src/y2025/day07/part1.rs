@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+use super::{Cell, Input, parse_input};
+use crate::answer::Answer;
+use crate::grid::{Grid, Point};
+
+fn count_splitters(position: Point, manifold: &Grid<Cell>, seen: &mut Grid<bool>) -> u64 {
+    let (x, y) = position;
+    for y in (y + 1)..manifold.height() {
+        if seen[(x, y)] {
+            return 0;
+        }
+
+        seen[(x, y)] = true;
+
+        if manifold[(x, y)] == Cell::Splitter {
+            let left = match x.checked_sub(1) {
+                Some(left_x) => count_splitters((left_x, y), manifold, seen),
+                None => 0,
+            };
+            let right = if x + 1 < manifold.width() {
+                count_splitters((x + 1, y), manifold, seen)
+            } else {
+                0
+            };
+            return left + right + 1;
+        }
+    }
+
+    0
+}
+
+pub fn solve((tachyon_start, manifold): &Input) -> Result<Answer> {
+    let mut seen = Grid::new(manifold.width(), manifold.height(), false);
+    let total_splits = count_splitters(*tachyon_start, manifold, &mut seen);
+
+    Ok(total_splits.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+
+use super::{Cell, Input, parse_input};
+use crate::answer::Answer;
+use crate::grid::{Grid, Point};
+
+fn count_worlds(pos: Point, manifold: &Grid<Cell>, cache: &mut Grid<Option<u64>>) -> Option<u64> {
+    let (x, y) = pos;
+
+    for y in (y + 1)..manifold.height() {
+        if manifold[(x, y)] == Cell::Splitter {
+            let left = match x.checked_sub(1) {
+                Some(left_x) => match cache[(left_x, y)] {
+                    Some(count) => count,
+                    None => count_worlds((left_x, y), manifold, cache)?,
+                },
+                None => 0,
+            };
+            let right = match cache.get((x + 1, y)) {
+                Some(Some(count)) => *count,
+                Some(None) => count_worlds((x + 1, y), manifold, cache)?,
+                None => 0,
+            };
+
+            let worlds = left.checked_add(right)?;
+            cache[pos] = Some(worlds);
+            return Some(worlds);
+        }
+    }
+
+    cache[pos] = Some(1);
+    Some(1)
+}
+
+pub fn solve((tachyon_start, manifold): &Input) -> Result<Answer> {
+    let mut cache = Grid::new(manifold.width(), manifold.height(), None);
+    let total_worlds = count_worlds(*tachyon_start, manifold, &mut cache)
+        .context("too many timelines to count")?;
+
+    Ok(total_worlds.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
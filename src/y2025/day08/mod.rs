@@ -0,0 +1,77 @@
+use anyhow::Result;
+
+use crate::parse;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Playground";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 8,
+        part: 1,
+        title: TITLE,
+        parse: parse_playground,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 8,
+        part: 2,
+        title: TITLE,
+        parse: parse_playground,
+        solve: part2::solve,
+    },
+];
+
+pub type Position = (u64, u64, u64);
+
+pub fn parse_positions(input: &str) -> Result<Vec<Position>> {
+    let mut positions = Vec::new();
+
+    for line in input.lines() {
+        let (x, rhs) = parse::split_once(line, ',')?;
+        let (y, z) = parse::split_once(rhs, ',')?;
+        positions.push((parse::number(x)?, parse::number(y)?, parse::number(z)?));
+    }
+
+    Ok(positions)
+}
+
+pub struct Playground {
+    pub positions: Vec<Position>,
+    pub pairs: Vec<(u128, usize, usize)>,
+}
+
+pub fn parse_playground(input: &str) -> Result<Playground> {
+    let positions = parse_positions(input)?;
+    let pairs = all_pairs_by_distance(&positions);
+
+    Ok(Playground { positions, pairs })
+}
+
+// Each squared axis fits in a u128; only the sum for coordinates near
+// u64::MAX can exceed it, and that saturates rather than wrapping.
+pub fn distance_squared(p1: Position, p2: Position) -> u128 {
+    let (x1, y1, z1) = p1;
+    let (x2, y2, z2) = p2;
+    [x1.abs_diff(x2), y1.abs_diff(y2), z1.abs_diff(z2)]
+        .into_iter()
+        .map(|d| (d as u128).pow(2))
+        .fold(0, u128::saturating_add)
+}
+
+pub fn all_pairs_by_distance(positions: &[Position]) -> Vec<(u128, usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..positions.len() {
+        for j in (i + 1)..positions.len() {
+            let distance = distance_squared(positions[i], positions[j]);
+            pairs.push((distance, i, j));
+        }
+    }
+    pairs.sort_by_key(|(d, _, _)| *d);
+    pairs
+}
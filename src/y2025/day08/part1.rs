@@ -0,0 +1,29 @@
+use anyhow::{Result, bail};
+
+use super::{Playground, parse_playground};
+use crate::answer::Answer;
+use crate::dsu::DisjointSet;
+
+pub fn solve(playground: &Playground) -> Result<Answer> {
+    let Playground { positions, pairs } = playground;
+    if positions.is_empty() {
+        bail!("no junction boxes");
+    }
+    let connection_count = 10_usize.pow(positions.len().ilog10());
+
+    let mut circuits = DisjointSet::new(positions.len());
+
+    for &(_, i, j) in pairs.iter().take(connection_count) {
+        circuits.union(i, j);
+    }
+
+    let mut sizes = circuits.component_sizes();
+    sizes.sort_by_key(|&s| std::cmp::Reverse(s));
+
+    let result = sizes.iter().take(3).product::<usize>();
+    Ok(result.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_playground(input)?)
+}
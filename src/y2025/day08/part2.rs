@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+
+use super::{Playground, parse_playground};
+use crate::answer::Answer;
+use crate::dsu::DisjointSet;
+
+fn find_last_connection(
+    pairs: &[(u128, usize, usize)],
+    circuits: &mut DisjointSet,
+) -> Option<(usize, usize)> {
+    for &(_, i, j) in pairs {
+        if circuits.union(i, j) && circuits.component_count() == 1 {
+            return Some((i, j));
+        }
+    }
+    None
+}
+
+pub fn solve(playground: &Playground) -> Result<Answer> {
+    let Playground { positions, pairs } = playground;
+
+    let mut circuits = DisjointSet::new(positions.len());
+
+    let (i, j) = find_last_connection(pairs, &mut circuits).context("no last connection found")?;
+
+    let result = positions[i]
+        .0
+        .checked_mul(positions[j].0)
+        .context("result overflows")?;
+    Ok(result.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_playground(input)?)
+}
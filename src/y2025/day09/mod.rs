@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::parse;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Movie Theater";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 9,
+        part: 1,
+        title: TITLE,
+        parse: parse_tiles,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 9,
+        part: 2,
+        title: TITLE,
+        parse: parse_tiles,
+        solve: part2::solve,
+    },
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    pub x: u64,
+    pub y: u64,
+}
+
+pub type Input = Vec<Tile>;
+
+pub fn parse_tiles(input: &str) -> Result<Input> {
+    let mut tiles = Vec::new();
+
+    for line in input.lines() {
+        let (x, y) = parse::split_once(line, ',')?;
+        tiles.push(Tile {
+            x: parse::number(x)?,
+            y: parse::number(y)?,
+        });
+    }
+
+    Ok(tiles)
+}
+
+pub fn get_tile_area(t1: &Tile, t2: &Tile) -> Option<u64> {
+    let width = t1.x.abs_diff(t2.x).checked_add(1)?;
+    let height = t1.y.abs_diff(t2.y).checked_add(1)?;
+    width.checked_mul(height)
+}
@@ -0,0 +1,23 @@
+use anyhow::{Context, Result};
+
+use super::{Input, get_tile_area, parse_tiles};
+use crate::answer::Answer;
+
+pub fn solve(tiles: &Input) -> Result<Answer> {
+    let mut largest_area = 0;
+    for i in 0..tiles.len() {
+        for j in (i + 1)..tiles.len() {
+            let area = get_tile_area(&tiles[i], &tiles[j]).context("tile area overflows")?;
+
+            if area > largest_area {
+                largest_area = area;
+            }
+        }
+    }
+
+    Ok(largest_area.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_tiles(input)?)
+}
@@ -1,8 +1,11 @@
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 
-use super::{Tile, get_tile_area, parse_tiles};
+use super::{Input, Tile, get_tile_area, parse_tiles};
+use crate::answer::Answer;
 
-fn build_polygon_edges(tiles: &[Tile]) -> Result<(Vec<(u64, u64, u64)>, Vec<(u64, u64, u64)>)> {
+type Edge = (u64, u64, u64);
+
+fn build_polygon_edges(tiles: &[Tile]) -> Result<(Vec<Edge>, Vec<Edge>)> {
     let mut vertical_edges = Vec::new();
     let mut horizontal_edges = Vec::new();
 
@@ -37,8 +40,8 @@ fn build_polygon_edges(tiles: &[Tile]) -> Result<(Vec<(u64, u64, u64)>, Vec<(u64
 fn rectangle_crosses_polygon_edge(
     corner1: &Tile,
     corner2: &Tile,
-    vertical_edges: &[(u64, u64, u64)],
-    horizontal_edges: &[(u64, u64, u64)],
+    vertical_edges: &[Edge],
+    horizontal_edges: &[Edge],
 ) -> bool {
     let min_x = corner1.x.min(corner2.x);
     let max_x = corner1.x.max(corner2.x);
@@ -64,9 +67,8 @@ fn rectangle_crosses_polygon_edge(
     false
 }
 
-pub fn run(input: &str) -> Result<String> {
-    let tiles = parse_tiles(input)?;
-    let (vertical_edges, horizontal_edges) = build_polygon_edges(&tiles)?;
+pub fn solve(tiles: &Input) -> Result<Answer> {
+    let (vertical_edges, horizontal_edges) = build_polygon_edges(tiles)?;
 
     let mut largest_area = 0;
     for i in 0..tiles.len() {
@@ -79,32 +81,16 @@ pub fn run(input: &str) -> Result<String> {
                 continue;
             }
 
-            let area = get_tile_area(corner1, corner2);
+            let area = get_tile_area(corner1, corner2).context("tile area overflows")?;
             if area > largest_area {
                 largest_area = area;
             }
         }
     }
 
-    Ok(largest_area.to_string())
+    Ok(largest_area.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example() -> Result<()> {
-        let input = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-        assert_eq!(run(input)?, "24".to_string());
-
-        Ok(())
-    }
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_tiles(input)?)
 }
@@ -0,0 +1,201 @@
+use anyhow::{Result, bail};
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Factory";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 10,
+        part: 1,
+        title: TITLE,
+        parse: parse_machines,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 10,
+        part: 2,
+        title: TITLE,
+        parse: parse_machines,
+        solve: part2::solve,
+    },
+];
+
+#[derive(Debug)]
+pub struct Machine {
+    pub indicator: Vec<Lights>,
+    pub buttons: Vec<Button>,
+    pub joltage_requirements: Vec<Joltage>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lights {
+    On,
+    Off,
+}
+
+impl Lights {
+    pub fn toggled(self) -> Self {
+        match self {
+            Lights::On => Lights::Off,
+            Lights::Off => Lights::On,
+        }
+    }
+}
+
+pub type Button = Vec<u8>;
+
+pub type Joltage = u16;
+
+// How many times each button of a machine is pressed.
+pub type Presses = Vec<u64>;
+
+impl Machine {
+    pub fn lights_after(&self, presses: &[u64]) -> Vec<Lights> {
+        let mut lights = vec![Lights::Off; self.indicator.len()];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            if count % 2 == 1 {
+                for &light in button {
+                    lights[light as usize] = lights[light as usize].toggled();
+                }
+            }
+        }
+        lights
+    }
+
+    pub fn joltages_after(&self, presses: &[u64]) -> Vec<u64> {
+        let mut joltages = vec![0_u64; self.joltage_requirements.len()];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for &counter in button {
+                joltages[counter as usize] = joltages[counter as usize].saturating_add(count);
+            }
+        }
+        joltages
+    }
+}
+
+fn strip_delimiters(s: &str, open: char, close: char) -> Result<&str> {
+    s.strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| ParseError::new(s, format!("expected {}...{}", open, close)).into())
+}
+
+fn parse_indicator(indicator: &str) -> Result<Vec<Lights>> {
+    let lights = strip_delimiters(indicator, '[', ']')?;
+    lights
+        .char_indices()
+        .map(|(i, c)| match c {
+            '.' => Ok(Lights::Off),
+            '#' => Ok(Lights::On),
+            _ => Err(ParseError::new(
+                &lights[i..i + c.len_utf8()],
+                format!("invalid light {:?}", c),
+            )
+            .into()),
+        })
+        .collect()
+}
+
+fn parse_button(button: &str, lights: usize) -> Result<Button> {
+    let mut toggled = Vec::new();
+    for index in strip_delimiters(button, '(', ')')?.split(',') {
+        let light = parse::number::<u8>(index)?;
+        if light as usize >= lights {
+            bail!(ParseError::new(
+                index,
+                format!("no light {} in a machine with {} lights", light, lights)
+            ));
+        }
+        if toggled.contains(&light) {
+            bail!(ParseError::new(
+                index,
+                format!("light {} is listed twice", light)
+            ));
+        }
+        toggled.push(light);
+    }
+    Ok(toggled)
+}
+
+fn parse_buttons(buttons: &str, lights: usize) -> Result<Vec<Button>> {
+    buttons
+        .split_whitespace()
+        .map(|button| parse_button(button, lights))
+        .collect::<Result<Vec<_>>>()
+}
+
+fn parse_joltage_requirements(joltage_requirements: &str) -> Result<Vec<Joltage>> {
+    strip_delimiters(joltage_requirements, '{', '}')?
+        .split(',')
+        .map(parse::number::<Joltage>)
+        .collect::<Result<Vec<_>>>()
+}
+
+pub fn parse_machine(line: &str) -> Result<Machine> {
+    let (indicator, rhs) = parse::split_once(line, ' ')?;
+    let Some(joltage_index) = rhs.find('{') else {
+        bail!(ParseError::new(parse::end(rhs), "expected '{'"));
+    };
+    let (buttons, joltages) = rhs.split_at(joltage_index);
+    let joltages = joltages.trim_end();
+
+    let indicator = parse_indicator(indicator)?;
+    let buttons = parse_buttons(buttons, indicator.len())?;
+    let joltage_requirements = parse_joltage_requirements(joltages)?;
+    if joltage_requirements.len() != indicator.len() {
+        bail!(ParseError::new(
+            joltages,
+            format!(
+                "expected {} joltage requirements, found {}",
+                indicator.len(),
+                joltage_requirements.len()
+            )
+        ));
+    }
+
+    Ok(Machine {
+        indicator,
+        buttons,
+        joltage_requirements,
+    })
+}
+
+pub type Input = Vec<Machine>;
+
+pub fn parse_machines(input: &str) -> Result<Input> {
+    input.lines().map(parse_machine).collect::<Result<Vec<_>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_presses() -> Result<()> {
+        let machine = parse_machine("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")?;
+        assert_eq!(machine.lights_after(&[0, 1, 0, 1, 0, 0]), machine.indicator);
+        assert_eq!(machine.lights_after(&[0, 3, 2, 1, 0, 0]), machine.indicator);
+        assert_eq!(
+            machine.joltages_after(&[1, 3, 0, 3, 1, 2]),
+            vec![3, 5, 4, 7]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_repeated_light_is_rejected() {
+        let input = "[..] (0,1,0) {0,0}";
+        let error = parse::locate(parse_machine(input).unwrap_err(), input);
+        assert!(
+            error
+                .to_string()
+                .starts_with("light 0 is listed twice at line 1, column 11")
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::{Context, Result, bail};
+
+use super::{Input, Lights, Machine, Presses, parse_machines};
+use crate::answer::Answer;
+use crate::gf2::System;
+
+// Pressing a button twice undoes it, so each button is pressed at most once
+// and the presses form a linear system over GF(2), one equation per light.
+pub fn press_plan(machine: &Machine) -> Result<Presses> {
+    if machine.indicator.len() > 64 {
+        bail!(
+            "{} lights is more than the 64 supported",
+            machine.indicator.len()
+        );
+    }
+
+    let buttons = machine
+        .buttons
+        .iter()
+        .map(|button| button.iter().fold(0_u64, |mask, &light| mask | 1 << light))
+        .collect::<Vec<_>>();
+    let target = machine
+        .indicator
+        .iter()
+        .enumerate()
+        .filter(|&(_, &light)| light == Lights::On)
+        .fold(0_u64, |mask, (light, _)| mask | 1 << light);
+
+    let presses = System::new(&buttons)
+        .min_weight_solution(target)?
+        .context("no combination of buttons lights the indicator")?
+        .into_iter()
+        .map(u64::from)
+        .collect::<Presses>();
+
+    if machine.lights_after(&presses) != machine.indicator {
+        bail!("pressing {:?} does not light the indicator", presses);
+    }
+    Ok(presses)
+}
+
+pub fn solve(machines: &Input) -> Result<Answer> {
+    let mut total: u64 = 0;
+    for (i, machine) in machines.iter().enumerate() {
+        let presses = press_plan(machine).with_context(|| format!("machine {}", i + 1))?;
+        total += presses.iter().sum::<u64>();
+    }
+
+    Ok(total.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_machines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+
+    use super::*;
+    use crate::y2025::day10::{Button, parse_machine, parse_machines};
+
+    fn apply_button(state: &[Lights], button: &Button) -> Vec<Lights> {
+        let mut new_state = state.to_vec();
+
+        for index in button {
+            new_state[*index as usize] = state[*index as usize].toggled();
+        }
+
+        new_state
+    }
+
+    // The breadth-first search this solver replaced, kept to check it against.
+    fn bfs_min_total_presses(machine: &Machine) -> Option<u64> {
+        let initial_state = vec![Lights::Off; machine.indicator.len()];
+
+        let mut queue = VecDeque::from([(initial_state.clone(), 0)]);
+        let mut visited = HashSet::from([initial_state]);
+
+        while let Some((state, presses)) = queue.pop_front() {
+            if state == machine.indicator {
+                return Some(presses);
+            }
+
+            for button in &machine.buttons {
+                let new_state = apply_button(&state, button);
+                if visited.insert(new_state.clone()) {
+                    queue.push_back((new_state, presses + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn test_matches_breadth_first_search() -> Result<()> {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = move |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        for _ in 0..200 {
+            let lights = 1 + next(8) as usize;
+            let indicator = (0..lights)
+                .map(|_| if next(2) == 0 { '.' } else { '#' })
+                .collect::<String>();
+            let buttons = (0..1 + next(10))
+                .map(|_| {
+                    let mut toggled = (0..lights)
+                        .filter(|_| next(3) == 0)
+                        .map(|light| light.to_string())
+                        .collect::<Vec<_>>();
+                    if toggled.is_empty() {
+                        toggled.push(next(lights as u64).to_string());
+                    }
+                    format!("({})", toggled.join(","))
+                })
+                .collect::<Vec<_>>();
+            let line = format!(
+                "[{}] {} {{{}}}",
+                indicator,
+                buttons.join(" "),
+                vec!["0"; lights].join(",")
+            );
+            let machine = parse_machine(&line)?;
+
+            assert_eq!(
+                press_plan(&machine)
+                    .ok()
+                    .map(|presses| presses.iter().sum::<u64>()),
+                bfs_min_total_presses(&machine),
+                "{}",
+                line
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_machine() -> Result<()> {
+        let line = format!(
+            "[{}] {} {{{}}}",
+            "#".repeat(64),
+            (0..64)
+                .map(|light| format!("({})", light))
+                .collect::<Vec<_>>()
+                .join(" "),
+            vec!["0"; 64].join(",")
+        );
+        assert_eq!(press_plan(&parse_machine(&line)?)?, vec![1; 64]);
+
+        let line = format!(
+            "[#.#.] {} {{0,0,0,0}}",
+            (0..30)
+                .map(|button| format!("({})", button % 4))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        let machine = parse_machine(&line)?;
+        assert_eq!(press_plan(&machine)?.iter().sum::<u64>(), 2);
+        assert_eq!(bfs_min_total_presses(&machine), Some(2));
+
+        let line = format!("[{}] (0) {{{}}}", ".".repeat(65), vec!["0"; 65].join(","));
+        assert!(press_plan(&parse_machine(&line)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreachable_indicator_is_an_error() -> Result<()> {
+        let machines = parse_machines("[.#] (0) {0,0}\n[##] (0,1) {0,0}\n")?;
+        assert_eq!(press_plan(&machines[1])?, vec![1]);
+
+        let error = solve(&machines).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "machine 1: no combination of buttons lights the indicator"
+        );
+        Ok(())
+    }
+}
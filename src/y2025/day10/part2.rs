@@ -0,0 +1,232 @@
+use anyhow::{Context, Result, bail};
+
+use super::{Input, Machine, Presses, parse_machines};
+use crate::answer::Answer;
+use crate::ilp;
+use crate::linalg::{Matrix, Rational};
+
+// A button can't be pressed more often than the smallest requirement among
+// the counters it increases.
+fn press_limits(machine: &Machine) -> Vec<i64> {
+    machine
+        .buttons
+        .iter()
+        .map(|button| {
+            button
+                .iter()
+                .map(|&counter| machine.joltage_requirements[counter as usize] as i64)
+                .min()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+fn joltage_system(machine: &Machine) -> (Matrix, Vec<Rational>) {
+    let mut matrix = Matrix::zeros(machine.joltage_requirements.len(), machine.buttons.len());
+    for (i, button) in machine.buttons.iter().enumerate() {
+        for &j in button {
+            matrix[(j as usize, i)] = Rational::ONE;
+        }
+    }
+    let joltages = machine
+        .joltage_requirements
+        .iter()
+        .map(|&joltage| Rational::from(joltage as i64))
+        .collect();
+
+    (matrix, joltages)
+}
+
+pub fn press_plan(machine: &Machine) -> Result<Presses> {
+    let num_elements = machine.buttons.len();
+    let (matrix, joltages) = joltage_system(machine);
+
+    let solution = matrix
+        .solve(&joltages)?
+        .context("no combination of presses meets the joltage requirements")?;
+
+    let objective = vec![Rational::ONE; num_elements];
+    let presses = ilp::minimize(&solution, &objective, &press_limits(machine))?
+        .context("no combination of presses meets the joltage requirements")?
+        .into_iter()
+        .map(u64::try_from)
+        .collect::<Result<Presses, _>>()?;
+
+    let requirements = machine
+        .joltage_requirements
+        .iter()
+        .map(|&joltage| joltage as u64)
+        .collect::<Vec<_>>();
+    if machine.joltages_after(&presses) != requirements {
+        bail!(
+            "pressing {:?} does not meet the joltage requirements",
+            presses
+        );
+    }
+    Ok(presses)
+}
+
+pub fn solve(machines: &Input) -> Result<Answer> {
+    let mut total: u64 = 0;
+    for (i, machine) in machines.iter().enumerate() {
+        let presses = press_plan(machine).with_context(|| format!("machine {}", i + 1))?;
+        total = presses
+            .iter()
+            .try_fold(total, |sum, &p| sum.checked_add(p))
+            .context("too many presses to count")?;
+    }
+
+    Ok(total.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_machines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::GeneralSolution;
+    use crate::y2025::day10::{parse_machine, parse_machines};
+
+    // The exhaustive search this solver replaced, kept to check it against.
+    fn find_minimum_presses(
+        solution: &GeneralSolution,
+        free_values: &mut Vec<i64>,
+        max_val: i64,
+        current_best: Option<i64>,
+    ) -> Result<Option<i64>> {
+        let partial_sum: i64 = free_values.iter().sum();
+        if let Some(best) = current_best
+            && partial_sum >= best
+        {
+            return Ok(None);
+        }
+
+        if free_values.len() == solution.free_columns.len() {
+            let free_values = free_values
+                .iter()
+                .map(|&v| Rational::from(v))
+                .collect::<Vec<_>>();
+            let values = solution.evaluate(&free_values)?;
+            let mut sum = partial_sum;
+
+            for &column in &solution.pivot_columns {
+                let Some(val) = values[column].to_integer() else {
+                    return Ok(None);
+                };
+                if val < 0 {
+                    return Ok(None);
+                }
+                sum = sum.checked_add(val).context("too many presses to count")?;
+                if let Some(best) = current_best
+                    && sum >= best
+                {
+                    return Ok(None);
+                }
+            }
+
+            return Ok(Some(sum));
+        }
+
+        let mut min = current_best;
+        for v in 0..=max_val {
+            free_values.push(v);
+            if let Some(result) = find_minimum_presses(solution, free_values, max_val, min)? {
+                min = Some(min.map_or(result, |m| m.min(result)));
+            }
+            free_values.pop();
+        }
+        Ok(min)
+    }
+
+    fn exhaustive_min_presses(machine: &Machine) -> Result<Option<i64>> {
+        let (matrix, joltages) = joltage_system(machine);
+        let Some(solution) = matrix.solve(&joltages)? else {
+            return Ok(None);
+        };
+        let max_val = machine
+            .joltage_requirements
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or(0) as i64;
+        find_minimum_presses(&solution, &mut Vec::new(), max_val, None)
+    }
+
+    // Deterministic pseudo-random machines with more buttons than counters,
+    // so most have several free variables.
+    fn generated_machines() -> Vec<Machine> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        let mut machines = Vec::new();
+        for _ in 0..40 {
+            let counters = 3 + next(3) as usize;
+            let buttons = counters + 1 + next(3) as usize;
+            let mut presses = vec![0; buttons];
+            let mut button_text = Vec::new();
+            let mut joltages = vec![0; counters];
+            for press in presses.iter_mut() {
+                *press = next(6);
+                let mut lights = (0..counters).filter(|_| next(2) == 0).collect::<Vec<_>>();
+                if lights.is_empty() {
+                    lights.push(next(counters as u64) as usize);
+                }
+                for &light in &lights {
+                    joltages[light] += *press;
+                }
+                let lights = lights.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+                button_text.push(format!("({})", lights.join(",")));
+            }
+
+            let line = format!(
+                "[{}] {} {{{}}}",
+                ".".repeat(counters),
+                button_text.join(" "),
+                joltages
+                    .iter()
+                    .map(|j| j.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+            machines.push(parse_machine(&line).unwrap());
+        }
+        machines
+    }
+
+    #[test]
+    fn test_matches_exhaustive_search() -> Result<()> {
+        for machine in generated_machines() {
+            let presses = press_plan(&machine)?.iter().sum::<u64>() as i64;
+
+            assert_eq!(
+                Some(presses),
+                exhaustive_min_presses(&machine)?,
+                "{:?}",
+                machine
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreachable_joltages_are_an_error() -> Result<()> {
+        let machines =
+            parse_machines("[..] (0,1) {1,1}\n[..] (0,1) {1,2}\n[..] (0) (0,1) {2,1}\n")?;
+        assert_eq!(press_plan(&machines[0])?, vec![1]);
+        assert_eq!(press_plan(&machines[2])?, vec![1, 1]);
+
+        let error = solve(&machines).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "machine 2: no combination of presses meets the joltage requirements"
+        );
+        Ok(())
+    }
+}
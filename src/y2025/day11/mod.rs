@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::parse;
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Reactor";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 11,
+        part: 1,
+        title: TITLE,
+        parse: parse_input,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 11,
+        part: 2,
+        title: TITLE,
+        parse: parse_input,
+        solve: part2::solve,
+    },
+];
+
+pub type Input = HashMap<String, Vec<String>>;
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    let mut map = HashMap::new();
+
+    for line in input.lines() {
+        let (key, value) = parse::split_once(line, ':')?;
+        let value: Vec<_> = value.split_whitespace().map(str::to_string).collect();
+        map.insert(key.to_string(), value);
+    }
+
+    Ok(map)
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result, bail};
+
+use super::{Input, parse_input};
+use crate::answer::Answer;
+
+const START_DEVICE: &str = "you";
+const END_DEVICE: &str = "out";
+
+fn count_possible_paths<'a>(
+    device_outputs: &'a Input,
+    device: &'a str,
+    visiting: &mut HashSet<&'a str>,
+) -> Result<u32> {
+    if device == END_DEVICE {
+        return Ok(1);
+    }
+    if !visiting.insert(device) {
+        bail!("cycle through device {}", device);
+    }
+
+    let outputs = device_outputs.get(device).context("device not found")?;
+
+    let mut count: u32 = 0;
+    for output in outputs {
+        count = count
+            .checked_add(count_possible_paths(device_outputs, output, visiting)?)
+            .context("too many paths to count")?;
+    }
+
+    visiting.remove(device);
+    Ok(count)
+}
+
+pub fn solve(device_outputs: &Input) -> Result<Answer> {
+    let possible_paths = count_possible_paths(device_outputs, START_DEVICE, &mut HashSet::new())?;
+
+    Ok(possible_paths.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
@@ -1,18 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 
-use super::parse_input;
+use super::{Input, parse_input};
+use crate::answer::Answer;
 
 const START_DEVICE: &str = "svr";
 const END_DEVICE: &str = "out";
 const NECESSARY_DEVICES: [&str; 2] = ["dac", "fft"];
 
 fn count_possible_paths<'a>(
-    device_outputs: &HashMap<&'a str, Vec<&'a str>>,
+    device_outputs: &'a Input,
     device: &'a str,
     seen: [bool; 2],
     memo: &mut HashMap<(&'a str, [bool; 2]), u64>,
+    visiting: &mut HashSet<&'a str>,
 ) -> Result<u64> {
     let seen = [
         seen[0] || device == NECESSARY_DEVICES[0],
@@ -27,51 +29,42 @@ fn count_possible_paths<'a>(
         return Ok(count);
     }
 
+    if !visiting.insert(device) {
+        bail!("cycle through device {}", device);
+    }
+
     let outputs = device_outputs.get(device).context("device not found")?;
 
-    let mut count = 0;
+    let mut count: u64 = 0;
     for output in outputs {
-        count += count_possible_paths(device_outputs, output, seen, memo)?;
+        count = count
+            .checked_add(count_possible_paths(
+                device_outputs,
+                output,
+                seen,
+                memo,
+                visiting,
+            )?)
+            .context("too many paths to count")?;
     }
 
+    visiting.remove(device);
     memo.insert((device, seen), count);
     Ok(count)
 }
 
-pub fn run(input: &str) -> Result<String> {
-    let device_outputs = parse_input(input)?;
-
+pub fn solve(device_outputs: &Input) -> Result<Answer> {
     let possible_paths = count_possible_paths(
-        &device_outputs,
+        device_outputs,
         START_DEVICE,
         [false, false],
         &mut HashMap::new(),
+        &mut HashSet::new(),
     )?;
 
-    Ok(possible_paths.to_string())
+    Ok(possible_paths.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example() -> Result<()> {
-        let input = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-        assert_eq!(run(input)?, "2".to_string());
-
-        Ok(())
-    }
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
 }
@@ -0,0 +1,124 @@
+use anyhow::{Result, bail};
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::solution::{Part, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub const TITLE: &str = "Christmas Tree Farm";
+
+pub static SOLUTIONS: [&dyn Solution; 2] = [
+    &Part {
+        year: super::YEAR,
+        day: 12,
+        part: 1,
+        title: TITLE,
+        parse: parse_input,
+        solve: part1::solve,
+    },
+    &Part {
+        year: super::YEAR,
+        day: 12,
+        part: 2,
+        title: TITLE,
+        parse: parse_input,
+        solve: part2::solve,
+    },
+];
+
+pub type Point = (u8, u8);
+
+pub type Shape = Vec<Point>;
+
+#[derive(Debug)]
+pub struct Region {
+    pub size: (u8, u8),
+    pub shape_counts: Vec<u8>,
+}
+
+pub type Input = (Vec<Shape>, Vec<Region>);
+
+fn next_line<'a>(lines: &mut impl Iterator<Item = &'a str>, input: &'a str) -> Result<&'a str> {
+    lines
+        .next()
+        .ok_or_else(|| ParseError::new(parse::end(input), "unexpected end of input").into())
+}
+
+const SHAPE_SIZE: usize = 3;
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    let mut lines = input.lines().peekable();
+
+    let mut shapes: Vec<Shape> = Vec::new();
+    loop {
+        next_line(&mut lines, input)?;
+
+        let mut rows = Vec::with_capacity(SHAPE_SIZE);
+        for _ in 0..SHAPE_SIZE {
+            let row = next_line(&mut lines, input)?;
+            if let Some((x, _)) = row.char_indices().nth(SHAPE_SIZE) {
+                bail!(ParseError::new(
+                    &row[x..],
+                    format!("shapes are {} cells wide", SHAPE_SIZE)
+                ));
+            }
+            rows.push(row);
+        }
+
+        let cells = Grid::from_lines(rows, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let shape = cells
+            .iter()
+            .filter(|&(_, &filled)| filled)
+            .map(|((x, y), _)| (x as u8, y as u8))
+            .collect();
+
+        shapes.push(normalize_shape(shape));
+
+        next_line(&mut lines, input)?;
+
+        let Some(line) = lines.peek() else {
+            break;
+        };
+        if line.contains('x') {
+            break;
+        }
+    }
+
+    let mut regions: Vec<Region> = Vec::new();
+    for line in lines {
+        let (dimensions, counts) = parse::split_once(line, ':')?;
+        let (width, height) = parse::split_once(dimensions, 'x')?;
+        let width = parse::number::<u8>(width)?;
+        let height = parse::number::<u8>(height)?;
+
+        let counts = counts.split_whitespace().collect::<Vec<_>>();
+        if let Some(extra) = counts.get(shapes.len()) {
+            bail!(ParseError::new(
+                extra,
+                format!("only {} shapes are defined", shapes.len())
+            ));
+        }
+        let shape_counts = counts
+            .into_iter()
+            .map(parse::number::<u8>)
+            .collect::<Result<Vec<_>>>()?;
+
+        regions.push(Region {
+            size: (width, height),
+            shape_counts,
+        });
+    }
+
+    Ok((shapes, regions))
+}
+
+pub fn normalize_shape(mut shape: Shape) -> Shape {
+    shape.sort();
+    shape
+}
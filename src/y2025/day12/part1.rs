@@ -0,0 +1,200 @@
+use anyhow::Result;
+
+use super::{Input, Region, Shape, normalize_shape, parse_input};
+use crate::answer::Answer;
+use crate::grid::Grid;
+
+fn fits_in_area(region: &Region, shapes: &[Shape]) -> bool {
+    let available = region.size.0 as u32 * region.size.1 as u32;
+
+    let mut required = 0;
+    for (shape_id, &count) in region.shape_counts.iter().enumerate() {
+        required += shapes[shape_id].len() as u32 * count as u32;
+    }
+
+    required <= available
+}
+
+fn rotate_right(shape: &Shape) -> Shape {
+    let mut min_x = 0;
+    let mut min_y = 0;
+
+    let mut new_shape = Vec::new();
+    for (x, y) in shape {
+        let new_x = *y as i8;
+        let new_y = -(*x as i8);
+
+        new_shape.push((new_x, new_y));
+
+        if new_x < min_x {
+            min_x = new_x;
+        }
+        if new_y < min_y {
+            min_y = new_y;
+        }
+    }
+
+    let new_shape = new_shape
+        .into_iter()
+        .map(|(x, y)| ((x - min_x) as u8, (y - min_y) as u8))
+        .collect::<Vec<_>>();
+
+    normalize_shape(new_shape)
+}
+
+fn flip_horizontal(shape: &Shape) -> Shape {
+    let mut min_x = 0;
+
+    let mut new_shape = Vec::new();
+    for (x, y) in shape {
+        let new_x = -(*x as i8);
+        let new_y = *y as i8;
+
+        new_shape.push((new_x, new_y));
+
+        if new_x < min_x {
+            min_x = new_x;
+        }
+    }
+
+    let new_shape = new_shape
+        .into_iter()
+        .map(|(x, y)| ((x - min_x) as u8, y as u8))
+        .collect::<Vec<_>>();
+
+    normalize_shape(new_shape)
+}
+
+fn get_orientations(shape: &Shape) -> Vec<Shape> {
+    let r0 = shape.clone();
+    let r1 = rotate_right(&r0);
+    let r2 = rotate_right(&r1);
+    let r3 = rotate_right(&r2);
+
+    let h0 = flip_horizontal(shape);
+    let h1 = rotate_right(&h0);
+    let h2 = rotate_right(&h1);
+    let h3 = rotate_right(&h2);
+
+    let mut orientations = Vec::new();
+    for s in [r0, r1, r2, r3, h0, h1, h2, h3] {
+        if !orientations.contains(&s) {
+            orientations.push(s);
+        }
+    }
+
+    orientations
+}
+
+fn shapes_to_place(region: &Region, orientations: &[Vec<Shape>]) -> Vec<usize> {
+    let mut shape_ids = Vec::new();
+    for (shape_id, &count) in region.shape_counts.iter().enumerate() {
+        for _ in 0..count {
+            shape_ids.push(shape_id);
+        }
+    }
+    shape_ids.sort_by_key(|&id| std::cmp::Reverse(orientations[id].len()));
+    shape_ids
+}
+
+// An orientation laid out for one grid: the offsets of its cells from its
+// top-left corner in the grid's cells, and the corners it can start from.
+struct Placement {
+    offsets: Vec<usize>,
+    max_x: usize,
+    max_y: usize,
+}
+
+impl Placement {
+    fn new(shape: &Shape, grid: &Grid<bool>) -> Option<Self> {
+        let shape_width = shape.iter().map(|&(x, _)| x as usize + 1).max()?;
+        let shape_height = shape.iter().map(|&(_, y)| y as usize + 1).max()?;
+        Some(Self {
+            offsets: shape
+                .iter()
+                .map(|&(x, y)| y as usize * grid.width() + x as usize)
+                .collect(),
+            max_x: grid.width().checked_sub(shape_width)?,
+            max_y: grid.height().checked_sub(shape_height)?,
+        })
+    }
+}
+
+fn can_place(placement: &Placement, start: usize, cells: &[bool]) -> bool {
+    placement
+        .offsets
+        .iter()
+        .all(|&offset| !cells[start + offset])
+}
+
+fn set(placement: &Placement, start: usize, cells: &mut [bool], filled: bool) {
+    for &offset in &placement.offsets {
+        cells[start + offset] = filled;
+    }
+}
+
+fn pack(
+    shape_ids: &[usize],
+    idx: usize,
+    grid: &mut Grid<bool>,
+    placements: &[Vec<Placement>],
+) -> bool {
+    if idx == shape_ids.len() {
+        return true;
+    }
+
+    let width = grid.width();
+    for placement in &placements[shape_ids[idx]] {
+        for y in 0..=placement.max_y {
+            for x in 0..=placement.max_x {
+                let start = y * width + x;
+                if can_place(placement, start, grid.cells()) {
+                    set(placement, start, grid.cells_mut(), true);
+                    if pack(shape_ids, idx + 1, grid, placements) {
+                        return true;
+                    }
+                    set(placement, start, grid.cells_mut(), false);
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn build_orientations(shapes: &[Shape]) -> Vec<Vec<Shape>> {
+    shapes.iter().map(get_orientations).collect()
+}
+
+pub fn solve((shapes, regions): &Input) -> Result<Answer> {
+    let orientations = build_orientations(shapes);
+
+    let mut valid_regions: u64 = 0;
+    for region in regions {
+        if !fits_in_area(region, shapes) {
+            continue;
+        }
+
+        let mut grid = Grid::new(region.size.0 as usize, region.size.1 as usize, false);
+        let shape_ids = shapes_to_place(region, &orientations);
+        let placements = orientations
+            .iter()
+            .map(|shapes| {
+                shapes
+                    .iter()
+                    .filter_map(|shape| Placement::new(shape, &grid))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if pack(&shape_ids, 0, &mut grid, &placements) {
+            valid_regions += 1;
+        }
+    }
+
+    Ok(valid_regions.into())
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
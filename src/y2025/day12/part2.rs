@@ -0,0 +1,12 @@
+use anyhow::Result;
+
+use super::{Input, parse_input};
+use crate::answer::Answer;
+
+pub fn solve(_input: &Input) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+pub fn run(input: &str) -> Result<Answer> {
+    solve(&parse_input(input)?)
+}
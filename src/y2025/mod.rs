@@ -0,0 +1,35 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+use crate::registry::Calendar;
+
+pub const YEAR: u16 = 2025;
+
+pub static CALENDAR: Calendar = Calendar {
+    year: YEAR,
+    days: 12,
+    solutions: &[
+        &day01::SOLUTIONS,
+        &day02::SOLUTIONS,
+        &day03::SOLUTIONS,
+        &day04::SOLUTIONS,
+        &day05::SOLUTIONS,
+        &day06::SOLUTIONS,
+        &day07::SOLUTIONS,
+        &day08::SOLUTIONS,
+        &day09::SOLUTIONS,
+        &day10::SOLUTIONS,
+        &day11::SOLUTIONS,
+        &day12::SOLUTIONS,
+    ],
+};
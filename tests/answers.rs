@@ -0,0 +1,83 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use anyhow::Result;
+use aoc::answers::{self, Answers, Verdict};
+use aoc::{input, runner};
+
+#[test]
+fn test_answers() -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let inputs_dir = root.join("inputs");
+    let answers = Answers::load(&root.join(answers::DEFAULT_ANSWERS_FILE))?;
+    let mut checked = HashSet::new();
+
+    for outcome in runner::run_all(&inputs_dir, None) {
+        let year = outcome.solution.year();
+        let day = outcome.solution.day();
+        let part = outcome.solution.part();
+
+        match answers::verify(&outcome, &answers) {
+            Verdict::Correct => {
+                checked.insert((year, day, part));
+            }
+            // Other parts of the day have answers for this input, so this one
+            // should too.
+            Verdict::Unknown
+                if outcome
+                    .answer
+                    .as_ref()
+                    .is_ok_and(|answer| answer.is_solved())
+                    && outcome.input_hash.as_deref().is_some_and(|input_hash| {
+                        answers.entries_for(input_hash, year, day).next().is_some()
+                    }) =>
+            {
+                panic!("{} day {} part {}: no answer recorded", year, day, part)
+            }
+            Verdict::Unknown => (),
+            Verdict::Incorrect { expected } => panic!(
+                "{} day {} part {}: expected {}, got {}",
+                year, day, part, expected, outcome.answer?
+            ),
+            Verdict::Failed if outcome.input_hash.is_none() => (),
+            Verdict::Failed => {
+                outcome.answer?;
+            }
+        }
+    }
+
+    // The input on disk must match one of its day's answers, and every answer
+    // for it must have been checked, so a stale hash or a part that stops
+    // running fails instead of passing unnoticed.
+    let days = answers
+        .entries
+        .iter()
+        .map(|entry| (entry.year, entry.day))
+        .collect::<BTreeSet<_>>();
+    for (year, day) in days {
+        let Ok(text) = input::read_input(&inputs_dir, year, day) else {
+            eprintln!("{} day {}: skipped, no input file", year, day);
+            continue;
+        };
+        let entries = answers
+            .entries_for(&input::hash(&text), year, day)
+            .collect::<Vec<_>>();
+        assert!(
+            !entries.is_empty(),
+            "{} day {}: no answer matches the input on disk",
+            year,
+            day
+        );
+        for entry in entries {
+            assert!(
+                checked.contains(&(year, day, entry.part)),
+                "{} day {} part {}: answer was never checked",
+                year,
+                day,
+                entry.part
+            );
+        }
+    }
+
+    Ok(())
+}
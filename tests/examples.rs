@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use anyhow::Result;
+use aoc::examples::{self, Examples};
+use aoc::{registry, runner};
+
+#[test]
+fn test_examples() -> Result<()> {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(examples::DEFAULT_EXAMPLES_DIR);
+    let mut failures = Vec::new();
+
+    for solution in registry::solutions() {
+        let (year, day, part) = (solution.year(), solution.day(), solution.part());
+        let examples = Examples::load(&examples_dir, year, day)?;
+
+        for (index, (example, expected)) in examples.for_part(part).enumerate() {
+            match runner::run(solution, &example.input).answer {
+                Ok(answer) if answer == expected => (),
+                Ok(answer) => failures.push(format!(
+                    "{} day {} part {} example {}: expected {}, got {}",
+                    year,
+                    day,
+                    part,
+                    index + 1,
+                    expected,
+                    answer
+                )),
+                Err(error) => failures.push(format!(
+                    "{} day {} part {} example {}: {:#}",
+                    year,
+                    day,
+                    part,
+                    index + 1,
+                    error
+                )),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use aoc::{registry, runner, scaffold};
+
+fn fuzz_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(scaffold::DEFAULT_FUZZ_DIR)
+}
+
+#[test]
+fn test_every_day_has_a_fuzz_target() -> Result<()> {
+    let manifest = fs::read_to_string(fuzz_dir().join("Cargo.toml"))?;
+
+    for solution in registry::solutions() {
+        let name = scaffold::fuzz_target_name(solution.year(), solution.day());
+        assert!(
+            fuzz_dir()
+                .join("fuzz_targets")
+                .join(format!("{}.rs", name))
+                .exists(),
+            "no fuzz target for {} day {}",
+            solution.year(),
+            solution.day()
+        );
+        assert!(
+            manifest.contains(&format!("name = {:?}", name)),
+            "{} is missing from the fuzz manifest",
+            name
+        );
+    }
+    Ok(())
+}
+
+// Every input the fuzzers ever crashed on is kept under fuzz/regressions and
+// must now run to an answer or an error.
+#[test]
+fn test_fuzz_regressions() -> Result<()> {
+    for calendar in registry::calendars() {
+        for day in 1..=calendar.days {
+            let solutions = calendar
+                .solutions()
+                .filter(|solution| solution.day() == day)
+                .collect::<Vec<_>>();
+            let dir = fuzz_dir()
+                .join("regressions")
+                .join(scaffold::fuzz_target_name(calendar.year, day));
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries {
+                let path = entry?.path();
+                let Ok(input) = String::from_utf8(fs::read(&path)?) else {
+                    continue;
+                };
+                let run = panic::catch_unwind(AssertUnwindSafe(|| {
+                    runner::run_parts(&solutions, &input);
+                }));
+                assert!(run.is_ok(), "{} panicked", path.display());
+            }
+        }
+    }
+    Ok(())
+}
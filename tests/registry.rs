@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use aoc::registry;
+
+// A year may be scaffolded one day at a time, so only the days it registers
+// need both parts.
+#[test]
+fn test_every_day_is_registered() {
+    for calendar in registry::calendars() {
+        let days = calendar
+            .solutions()
+            .map(|solution| solution.day())
+            .collect::<HashSet<_>>();
+        for day in days {
+            for part in 1..=2 {
+                assert!(
+                    registry::find(calendar.year, day, part).is_some(),
+                    "{} day {} part {} is not registered",
+                    calendar.year,
+                    day,
+                    part
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_solutions_are_unique() {
+    let mut seen = HashSet::new();
+    for solution in registry::solutions() {
+        assert!(
+            seen.insert((solution.year(), solution.day(), solution.part())),
+            "{} day {} part {} is registered twice",
+            solution.year(),
+            solution.day(),
+            solution.part()
+        );
+    }
+}
+
+#[test]
+fn test_solutions_match_their_calendar() {
+    for calendar in registry::calendars() {
+        for solution in calendar.solutions() {
+            assert_eq!(solution.year(), calendar.year);
+            assert!((1..=calendar.days).contains(&solution.day()));
+        }
+    }
+}
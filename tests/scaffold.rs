@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Result, ensure};
+use aoc::scaffold;
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &path)?;
+        } else {
+            fs::copy(entry.path(), path)?;
+        }
+    }
+    Ok(())
+}
+
+// Scaffolds a day of a new year into a copy of the crate and checks that the
+// registry tests still pass there.
+#[test]
+fn test_scaffolded_year_keeps_the_registry_green() -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold");
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    copy_dir(&root.join("src"), &dir.join("src"))?;
+    fs::create_dir_all(dir.join("tests"))?;
+    fs::copy(
+        root.join("tests/registry.rs"),
+        dir.join("tests/registry.rs"),
+    )?;
+    for file in ["Cargo.toml", "Cargo.lock"] {
+        if root.join(file).exists() {
+            fs::copy(root.join(file), dir.join(file))?;
+        }
+    }
+
+    scaffold::create(
+        &dir.join("src"),
+        &dir.join("examples"),
+        &dir.join("fuzz"),
+        2024,
+        3,
+        "Mull It Over",
+    )?;
+
+    let status = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+        .args(["test", "--offline", "--quiet", "--test", "registry"])
+        .current_dir(&dir)
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold-target"),
+        )
+        .status()?;
+    ensure!(status.success(), "registry tests failed after scaffolding");
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}